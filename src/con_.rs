@@ -9,6 +9,19 @@ pub enum Con<'a, T: 'a> {
     Rc(Rc<T>),
 }
 
+impl<'a, T: Clone> Con<'a, T> {
+    /// Take the owned value, cloning it if necessary.
+    pub fn into_owned(self) -> T {
+        use self::Con::*;
+
+        match self {
+            Borrowed(value) => value.clone(),
+            Owned(value) => value,
+            Rc(value) => value.as_ref().clone(),
+        }
+    }
+}
+
 impl<'a, T> AsRef<T> for Con<'a, T> {
     fn as_ref(&self) -> &T {
         use self::Con::*;
@@ -1,28 +1,79 @@
 //! Helper trait to take ownership of strings.
 
 use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::rc::Rc;
 
 /// A managed string that permits immutable borrowing.
-#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+///
+/// Strings are compared and hashed by content, regardless of how they are stored.
+#[derive(Debug, Clone)]
 pub enum Cons<'el> {
     /// A borrowed string.
     Borrowed(&'el str),
     /// A refcounted string.
     Rc(Rc<String>),
+    /// A refcounted string slice, as handed out by an [`Interner`].
+    Shared(Rc<str>),
 }
 
-impl<'a> AsRef<str> for Cons<'a> {
-    fn as_ref(&self) -> &str {
+impl<'el> Cons<'el> {
+    /// Borrow the string, regardless of how it is stored.
+    pub fn as_str(&self) -> &str {
         use self::Cons::*;
 
         match *self {
             Borrowed(value) => value,
-            Rc(ref value) => value.as_ref(),
+            Rc(ref value) => value.as_str(),
+            Shared(ref value) => value,
         }
     }
+
+    /// Length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Check if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl<'a> PartialEq for Cons<'a> {
+    fn eq(&self, other: &Cons<'a>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<'a> Eq for Cons<'a> {}
+
+impl<'a> PartialOrd for Cons<'a> {
+    fn partial_cmp(&self, other: &Cons<'a>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Cons<'a> {
+    fn cmp(&self, other: &Cons<'a>) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<'a> Hash for Cons<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<'a> AsRef<str> for Cons<'a> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
 
 impl<'a> Deref for Cons<'a> {
@@ -51,6 +102,12 @@ impl<'el> From<Rc<String>> for Cons<'el> {
     }
 }
 
+impl<'el> From<Rc<str>> for Cons<'el> {
+    fn from(value: Rc<str>) -> Self {
+        Cons::Shared(value)
+    }
+}
+
 impl<'el> From<Cow<'el, str>> for Cons<'el> {
     fn from(value: Cow<'el, str>) -> Self {
         use self::Cow::*;
@@ -67,3 +124,100 @@ impl<'el> fmt::Display for Cons<'el> {
         self.as_ref().fmt(fmt)
     }
 }
+
+/// Deduplicates strings which recur, so that each is only allocated once.
+///
+/// Every string interned with the same content shares a single allocation.
+#[derive(Debug, Default)]
+pub struct Interner {
+    strings: HashSet<Rc<str>>,
+}
+
+impl Interner {
+    /// Get the shared string for the given value, allocating it on first use.
+    pub fn intern<'el, S: AsRef<str>>(&mut self, value: S) -> Cons<'el> {
+        let value = value.as_ref();
+
+        if let Some(shared) = self.strings.get(value) {
+            return Cons::Shared(shared.clone());
+        }
+
+        let shared: Rc<str> = Rc::from(value);
+        self.strings.insert(shared.clone());
+        Cons::Shared(shared)
+    }
+
+    /// Number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Check if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cons, Interner};
+
+    #[test]
+    fn test_as_str() {
+        let borrowed = Cons::from("foo");
+        let owned = Cons::from(String::from("foobar"));
+        let empty = Cons::from(String::new());
+
+        assert_eq!("foo", borrowed.as_str());
+        assert_eq!("foobar", owned.as_str());
+        assert_eq!(3, borrowed.len());
+        assert_eq!(6, owned.len());
+        assert!(!owned.is_empty());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::default();
+        let a = interner.intern("Foo");
+        let b = interner.intern(String::from("Foo"));
+        let c = interner.intern("Bar");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!("Foo", a.as_str());
+        assert_eq!(2, interner.len());
+
+        match (a, b) {
+            (Cons::Shared(a), Cons::Shared(b)) => assert!(::std::rc::Rc::ptr_eq(&a, &b)),
+            _ => panic!("expected shared strings"),
+        }
+    }
+
+    #[test]
+    fn test_eq_by_content() {
+        use java::{imported, Java};
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut interner = Interner::default();
+        let shared = interner.intern("Foo");
+        let borrowed = Cons::from("Foo");
+        let owned = Cons::from(String::from("Foo"));
+
+        assert_eq!(shared, borrowed);
+        assert_eq!(borrowed, owned);
+        assert_eq!(hash(&shared), hash(&borrowed));
+        assert!(Cons::from("Bar") < shared);
+
+        let a: Java = imported("com.example", interner.intern("Foo"));
+        let b: Java = imported("com.example", "Foo");
+        assert_eq!(a, b);
+    }
+}
@@ -551,16 +551,25 @@ impl<'el> Custom for Csharp<'el> {
         Ok(())
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
+    fn quote_char(out: &mut Formatter, _extra: &mut Self::Extra, c: char) -> fmt::Result {
+        out.write_char('\'')?;
+        escape_char(out, c)?;
+        out.write_char('\'')
+    }
+
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
         for c in input.chars() {
             match c {
                 '\t' => out.write_str("\\t")?,
-                '\u{0007}' => out.write_str("\\b")?,
+                '\u{0007}' => out.write_str("\\a")?,
+                '\u{0008}' => out.write_str("\\b")?,
                 '\n' => out.write_str("\\n")?,
                 '\r' => out.write_str("\\r")?,
-                '\u{0014}' => out.write_str("\\f")?,
+                '\u{000C}' => out.write_str("\\f")?,
                 '\'' => out.write_str("\\'")?,
                 '"' => out.write_str("\\\"")?,
                 '\\' => out.write_str("\\\\")?,
@@ -568,11 +577,13 @@ impl<'el> Custom for Csharp<'el> {
             }
         }
 
-        out.write_char('"')?;
-
         Ok(())
     }
 
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
     fn write_file<'a>(
         tokens: Tokens<'a, Self>,
         out: &mut Formatter,
@@ -634,6 +645,24 @@ pub fn optional<'el, I: Into<Csharp<'el>>>(value: I) -> Csharp<'el> {
     Csharp::Optional(Box::new(value.into()))
 }
 
+/// Escape a character for use in a character literal.
+fn escape_char(out: &mut Formatter, c: char) -> fmt::Result {
+    match c {
+        '\0' => out.write_str("\\0"),
+        '\u{0007}' => out.write_str("\\a"),
+        '\u{0008}' => out.write_str("\\b"),
+        '\u{000C}' => out.write_str("\\f"),
+        '\n' => out.write_str("\\n"),
+        '\r' => out.write_str("\\r"),
+        '\t' => out.write_str("\\t"),
+        '\u{000B}' => out.write_str("\\v"),
+        '\\' => out.write_str("\\\\"),
+        '\'' => out.write_str("\\'"),
+        c if c.is_control() => write!(out, "\\u{:04x}", c as u32),
+        c => out.write_char(c),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -664,6 +693,29 @@ mod tests {
         assert_eq!("\"hello \\n world\"", toks.to_string().unwrap().as_str());
     }
 
+    #[test]
+    fn test_string_control_escapes() {
+        let toks: Tokens<Csharp> = toks!["\u{0007}\u{0008}\u{000C}\u{0014}".quoted()];
+        assert_eq!("\"\\a\\b\\f\u{0014}\"", toks.to_string().unwrap().as_str());
+    }
+
+    #[test]
+    fn test_char() {
+        let toks: Tokens<Csharp> = toks![
+            'a'.quoted(),
+            '"'.quoted(),
+            '\''.quoted(),
+            '\u{000B}'.quoted(),
+            '\u{0001}'.quoted()
+        ]
+        .join_spacing();
+
+        assert_eq!(
+            "'a' '\"' '\\'' '\\v' '\\u0001'",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
     #[ignore]
     #[test]
     fn test_using() {
@@ -17,9 +17,57 @@ where
         Ok(())
     }
 
+    /// Write the delimiter that opens a quoted string.
+    fn write_quoted_open(_out: &mut Formatter) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Write the content of a quoted string, escaped according to convention set by custom
+    /// element.
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
+        out.write_str(input)
+    }
+
+    /// Write the delimiter that closes a quoted string.
+    fn write_quoted_close(_out: &mut Formatter) -> fmt::Result {
+        Ok(())
+    }
+
     /// Performing quoting according to convention set by custom element.
+    ///
+    /// Defaults to writing the open delimiter, the content and the close delimiter.
     fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_str(input)
+        Self::write_quoted_open(out)?;
+        Self::write_quoted_content(out, input)?;
+        Self::write_quoted_close(out)
+    }
+
+    /// Performing quoting with access to the extra data of the current format.
+    ///
+    /// Defaults to [`Custom::quote_string`].
+    fn quote_string_with(
+        out: &mut Formatter,
+        _extra: &mut Self::Extra,
+        input: &str,
+    ) -> fmt::Result {
+        Self::quote_string(out, input)
+    }
+
+    /// If statements are terminated with a `;`, as used by [`Tokens::statement`].
+    fn requires_trailing_semicolon() -> bool {
+        true
+    }
+
+    /// Prefix of a line comment, like `// ` or `# `.
+    fn line_comment_prefix() -> &'static str {
+        "// "
+    }
+
+    /// Performing quoting of a single character, as a character literal if the language has one.
+    ///
+    /// Defaults to quoting the character as a string with [`Custom::quote_string_with`].
+    fn quote_char(out: &mut Formatter, extra: &mut Self::Extra, c: char) -> fmt::Result {
+        Self::quote_string_with(out, extra, c.encode_utf8(&mut [0u8; 4]))
     }
 
     /// Write a file according to convention by custom element.
@@ -37,3 +85,86 @@ where
 impl Custom for () {
     type Extra = ();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Custom;
+    use formatter::Formatter;
+    use quoted::Quoted;
+    use std::fmt::{self, Write};
+    use tokens::Tokens;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Template;
+
+    impl Custom for Template {
+        type Extra = ();
+
+        fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+            out.write_char('`')
+        }
+
+        fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+            out.write_char('`')
+        }
+    }
+
+    #[test]
+    fn test_quoted_delimiters() {
+        let toks: Tokens<Template> = toks!["hello".quoted()];
+        assert_eq!("`hello`", toks.to_string().unwrap().as_str());
+
+        let toks: Tokens<()> = toks!["hello".quoted()];
+        assert_eq!("hello", toks.to_string().unwrap().as_str());
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Depth;
+
+    impl Custom for Depth {
+        type Extra = ();
+
+        fn format(&self, out: &mut Formatter, _: &mut (), _: usize) -> fmt::Result {
+            write!(out, "depth {}", out.indentation())
+        }
+    }
+
+    #[test]
+    fn test_indentation() {
+        let mut toks: Tokens<Depth> = Tokens::new();
+        toks.push(toks![Depth]);
+        toks.nested(toks![Depth]);
+
+        assert_eq!("depth 0\n  depth 1\n", toks.to_string().unwrap().as_str());
+    }
+
+    #[test]
+    fn test_comment() {
+        use element::Element;
+
+        let mut toks: Tokens<Template> = Tokens::new();
+        toks.push("fn foo() {");
+        toks.nested(toks![Element::Comment("hello\n\nworld".into()), "bar();"]);
+        toks.push("}");
+
+        assert_eq!(
+            "fn foo() {\n  // hello\n  //\n  // world\n  bar();\n}",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_empty_comment() {
+        use element::Element;
+
+        let mut toks: Tokens<Template> = Tokens::new();
+        toks.push("fn foo() {");
+        toks.nested(toks![Element::Comment("".into()), "bar();"]);
+        toks.push("}");
+
+        assert_eq!(
+            "fn foo() {\n  bar();\n}",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+}
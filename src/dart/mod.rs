@@ -238,16 +238,18 @@ impl<'el> Custom for Dart<'el> {
         Ok(())
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
         for c in input.chars() {
             match c {
                 '\t' => out.write_str("\\t")?,
-                '\u{0007}' => out.write_str("\\b")?,
+                '\u{0008}' => out.write_str("\\b")?,
                 '\n' => out.write_str("\\n")?,
                 '\r' => out.write_str("\\r")?,
-                '\u{0014}' => out.write_str("\\f")?,
+                '\u{000C}' => out.write_str("\\f")?,
                 '\'' => out.write_str("\\'")?,
                 '"' => out.write_str("\\\"")?,
                 '\\' => out.write_str("\\\\")?,
@@ -255,11 +257,13 @@ impl<'el> Custom for Dart<'el> {
             }
         }
 
-        out.write_char('"')?;
-
         Ok(())
     }
 
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
     fn write_file<'a>(
         tokens: Tokens<'a, Self>,
         out: &mut Formatter,
@@ -312,6 +316,15 @@ mod tests {
         assert_eq!("\"hello \\n world\"", toks.to_string().unwrap().as_str());
     }
 
+    #[test]
+    fn test_control_escapes() {
+        let toks: Tokens<Dart> = toks!["\u{0007}\u{0008}\u{000C}\u{0014}".quoted()];
+        assert_eq!(
+            "\"\u{0007}\\b\\f\u{0014}\"",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
     #[test]
     fn test_imported() {
         let import = imported("package:http/http.dart");
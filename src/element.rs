@@ -20,10 +20,20 @@ pub enum Element<'el, C: 'el> {
     Push(Con<'el, Tokens<'el, C>>),
     /// Append the given set of tokens in a nested way.
     Nested(Con<'el, Tokens<'el, C>>),
+    /// Append the given set of tokens, marked as one logical unit which layout should keep whole.
+    Group(Con<'el, Tokens<'el, C>>),
+    /// Append the given set of tokens, labeled with where they were generated for debugging.
+    Labeled(Cons<'el>, Con<'el, Tokens<'el, C>>),
     /// A borrowed string.
     Literal(Cons<'el>),
     /// A borrowed quoted string.
     Quoted(Cons<'el>),
+    /// A quoted character.
+    QuotedChar(char),
+    /// A string written exactly as-is, without indentation or quoting.
+    Verbatim(Cons<'el>),
+    /// A comment on its own lines, using the line comment prefix of the language.
+    Comment(Cons<'el>),
     /// Language-specific items.
     Custom(Con<'el, C>),
     /// A custom element that is not rendered.
@@ -38,6 +48,16 @@ pub enum Element<'el, C: 'el> {
     Spacing,
     /// New line if needed.
     LineSpacing,
+    /// New line if needed, followed by the given number of empty lines.
+    BlankLines(usize),
+    /// Rows pushed on their own lines, with the first column padded to a common width.
+    ///
+    /// Only the last line of the first column is measured.
+    Aligned(Vec<(Tokens<'el, C>, Tokens<'el, C>)>),
+    /// Increase the indentation level of the lines that follow.
+    Indent,
+    /// Decrease the indentation level of the lines that follow.
+    Unindent,
 }
 
 impl<'el, C: Custom> Element<'el, C> {
@@ -54,7 +74,7 @@ impl<'el, C: Custom> Element<'el, C> {
             Borrowed(element) => {
                 element.format(out, extra, level)?;
             }
-            Append(ref tokens) => {
+            Append(ref tokens) | Group(ref tokens) | Labeled(_, ref tokens) => {
                 tokens.as_ref().format(out, extra, level)?;
             }
             Nested(ref tokens) => {
@@ -73,8 +93,52 @@ impl<'el, C: Custom> Element<'el, C> {
             Literal(ref literal) => {
                 out.write_str(literal.as_ref())?;
             }
+            Aligned(ref rows) => {
+                let mut widths = Vec::with_capacity(rows.len());
+
+                for (left, _) in rows {
+                    let mut buffer = String::new();
+                    left.format(&mut Formatter::new(&mut buffer), extra, level)?;
+                    widths.push(buffer.rsplit('\n').next().map_or(0, |l| l.chars().count()));
+                }
+
+                let max = widths.iter().cloned().max().unwrap_or(0);
+
+                for ((left, right), width) in rows.iter().zip(widths) {
+                    out.new_line_unless_empty()?;
+                    left.format(out, extra, level)?;
+                    out.write_str(&" ".repeat(max - width + 1))?;
+                    right.format(out, extra, level)?;
+                }
+            }
+            Verbatim(ref verbatim) => {
+                out.write_verbatim(verbatim.as_ref())?;
+            }
             Quoted(ref literal) => {
-                C::quote_string(out, literal.as_ref())?;
+                C::quote_string_with(out, extra, literal.as_ref())?;
+            }
+            QuotedChar(c) => {
+                C::quote_char(out, extra, c)?;
+            }
+            Comment(ref comment) => {
+                if comment.as_ref().lines().next().is_none() {
+                    return Ok(());
+                }
+
+                let prefix = C::line_comment_prefix();
+
+                for line in comment.as_ref().lines() {
+                    out.new_line_unless_empty()?;
+
+                    if line.is_empty() {
+                        out.write_str(prefix.trim_end())?;
+                    } else {
+                        out.write_str(prefix)?;
+                        out.write_str(line)?;
+                    }
+                }
+
+                out.new_line()?;
             }
             Custom(ref custom) => {
                 custom.as_ref().format(out, extra, level)?;
@@ -90,9 +154,22 @@ impl<'el, C: Custom> Element<'el, C> {
                 out.new_line_unless_empty()?;
                 out.new_line()?;
             }
+            BlankLines(n) => {
+                out.new_line_unless_empty()?;
+
+                for _ in 0..n {
+                    out.new_line()?;
+                }
+            }
             Spacing => {
                 out.write_str(" ")?;
             }
+            Indent => {
+                out.indent();
+            }
+            Unindent => {
+                out.unindent();
+            }
         }
 
         Ok(())
@@ -0,0 +1,152 @@
+//! Writer for files which are built one declaration at a time.
+
+use super::custom::Custom;
+use super::formatter::Formatter;
+use super::tokens::Tokens;
+use std::fmt;
+
+/// Writes a file one top-level declaration at a time.
+///
+/// Declarations are held back until [`FileWriter::flush_imports`] is called, at which point the
+/// file header and imports for them are written. Any declaration pushed after that is written
+/// immediately, so only imports already known can be used by it.
+pub struct FileWriter<'write, 'el, C: 'el + Custom> {
+    out: Formatter<'write>,
+    extra: C::Extra,
+    /// Declarations waiting for the imports to be written.
+    pending: Option<Vec<Tokens<'el, C>>>,
+    /// If anything has been written after the imports.
+    written: bool,
+}
+
+impl<'write, 'el, C: Custom + Clone + Eq> FileWriter<'write, 'el, C> {
+    /// Create a new file writer.
+    pub fn new<W: fmt::Write>(write: &'write mut W, extra: C::Extra) -> FileWriter<'write, 'el, C> {
+        let mut out = Formatter::new(write);
+        out.trim_trailing_whitespace(true);
+
+        FileWriter {
+            out,
+            extra,
+            pending: Some(Vec::new()),
+            written: false,
+        }
+    }
+
+    /// Push a top-level declaration.
+    pub fn push(&mut self, tokens: Tokens<'el, C>) -> fmt::Result {
+        if let Some(ref mut pending) = self.pending {
+            pending.push(tokens);
+            return Ok(());
+        }
+
+        if self.written {
+            self.out.new_line_unless_empty()?;
+            self.out.new_line()?;
+        }
+
+        tokens.format(&mut self.out, &mut self.extra, 0usize)?;
+        self.written = true;
+        Ok(())
+    }
+
+    /// Write the file header and imports, followed by the declarations pushed so far.
+    ///
+    /// Does nothing if the imports have already been written.
+    pub fn flush_imports(&mut self) -> fmt::Result {
+        let pending = match self.pending.take() {
+            Some(pending) => pending,
+            None => return Ok(()),
+        };
+
+        let mut tokens = Tokens::new();
+
+        for t in pending {
+            tokens.push(t);
+        }
+
+        C::write_file(
+            tokens.join_line_spacing(),
+            &mut self.out,
+            &mut self.extra,
+            0usize,
+        )?;
+        self.written = true;
+        Ok(())
+    }
+
+    /// Finish the file, writing the imports if they haven't been written yet.
+    pub fn finish(mut self) -> fmt::Result {
+        self.flush_imports()?;
+        self.out.new_line_unless_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileWriter;
+    use java::{imported, Extra, Java};
+    use tokens::Tokens;
+
+    #[test]
+    fn test_file_writer() {
+        let list = imported("java.util", "List");
+        let map = imported("java.util", "Map");
+
+        let mut out = String::new();
+
+        {
+            let mut w: FileWriter<Java> = FileWriter::new(&mut out, Extra::new("foo"));
+            w.push(toks!["class A { ", list.clone(), " a; }"]).unwrap();
+            w.push(toks!["class B {}"]).unwrap();
+            w.flush_imports().unwrap();
+            w.push(toks!["class C { ", list, " c; ", map, " d; }"])
+                .unwrap();
+            w.finish().unwrap();
+        }
+
+        assert_eq!(
+            concat!(
+                "package foo;\n",
+                "\n",
+                "import java.util.List;\n",
+                "\n",
+                "class A { List a; }\n",
+                "\n",
+                "class B {}\n",
+                "\n",
+                "class C { List c; java.util.Map d; }\n",
+            ),
+            out
+        );
+    }
+
+    #[test]
+    fn test_same_as_to_file() {
+        let list = imported("java.util", "List");
+        let decls = vec![
+            toks!["class A { ", list.clone(), " a; }"],
+            toks!["class B {}"],
+        ];
+
+        let mut out = String::new();
+
+        {
+            let mut w: FileWriter<Java> = FileWriter::new(&mut out, Extra::default());
+
+            for decl in decls.clone() {
+                w.push(decl).unwrap();
+            }
+
+            w.finish().unwrap();
+        }
+
+        let mut toks: Tokens<Java> = Tokens::new();
+
+        for decl in decls {
+            toks.push(decl);
+        }
+
+        assert_eq!(toks.join_line_spacing().to_file().unwrap(), out);
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
 use std::io;
 use std::iter;
+use std::mem;
 
 /// Facade for writing formatted strings to io::Write types.
 pub struct IoFmt<'write, W: 'write>(pub &'write mut W);
@@ -19,10 +20,20 @@ pub struct Formatter<'write> {
     write: &'write mut fmt::Write,
     /// if last line was empty.
     current_line_empty: bool,
+    /// If the indentation of the current line has been written.
+    line_started: bool,
+    /// If trailing whitespace should be left out of every line.
+    trim: bool,
+    /// Trailing whitespace, held back until something else is written on the same line.
+    pending: String,
     /// Current indentation level.
     indent: usize,
     /// Holds the current indentation level as a string.
     buffer: String,
+    /// Called with each completed line before it is written.
+    line_hook: Option<&'write mut dyn FnMut(&mut String)>,
+    /// The line being built, when a line hook is set.
+    line: String,
 }
 
 impl<'write> Formatter<'write> {
@@ -31,38 +42,189 @@ impl<'write> Formatter<'write> {
         Formatter {
             write: write,
             current_line_empty: true,
+            line_started: false,
+            trim: false,
+            pending: String::new(),
             indent: 0usize,
             buffer: String::from("  "),
+            line_hook: None,
+            line: String::new(),
         }
     }
 
+    /// Create a new write formatter which passes each completed line through the given hook.
+    ///
+    /// The hook receives the line without its trailing newline and may modify it in place.
+    /// Output is buffered line-by-line, so call `flush` once done to write a trailing partial line.
+    /// Prefer `Tokens::format_with_line_hook` or `Tokens::to_file_with_line_hook`, which flush
+    /// automatically.
+    pub fn with_line_hook(
+        write: &'write mut dyn fmt::Write,
+        hook: &'write mut dyn FnMut(&mut String),
+    ) -> Formatter<'write> {
+        Formatter {
+            write,
+            current_line_empty: true,
+            line_started: false,
+            trim: false,
+            pending: String::new(),
+            indent: 0usize,
+            buffer: String::from("  "),
+            line_hook: Some(hook),
+            line: String::new(),
+        }
+    }
+
+    /// Leave out trailing whitespace from every line, including blank ones.
+    ///
+    /// Trailing whitespace is held back until something else is written on the same line, so
+    /// whitespace at the very end of the output is never written.
+    pub fn trim_trailing_whitespace(&mut self, trim: bool) {
+        self.trim = trim;
+    }
+
+    /// Write to the underlying output, or to the current line if it is being buffered.
+    fn emit(&mut self, s: &str) -> fmt::Result {
+        if self.line_hook.is_some() {
+            self.line.push_str(s);
+            Ok(())
+        } else {
+            self.write.write_str(s)
+        }
+    }
+
+    /// Pass the buffered line through the hook and write it.
+    fn end_line(&mut self) -> fmt::Result {
+        if let Some(ref mut hook) = self.line_hook {
+            hook(&mut self.line);
+            self.write.write_str(&self.line)?;
+            self.line.clear();
+        }
+
+        Ok(())
+    }
+
+    /// End the current line without resetting indentation state, discarding trailing whitespace.
+    fn raw_new_line(&mut self) -> fmt::Result {
+        self.pending.clear();
+        self.end_line()?;
+        self.write.write_char('\n')
+    }
+
     fn check_indent(&mut self) -> fmt::Result {
-        if self.current_line_empty && self.indent > 0 {
-            self.write.write_str(&self.buffer[0..self.indent * 2])?;
-            self.current_line_empty = false;
+        if !self.line_started {
+            self.line_started = true;
+
+            if self.indent > 0 {
+                let indent = self.indent * 2;
+
+                if self.line_hook.is_some() {
+                    self.line.push_str(&self.buffer[0..indent]);
+                } else {
+                    self.write.write_str(&self.buffer[0..indent])?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Write a piece of a line, holding back any trailing whitespace.
+    fn write_segment(&mut self, s: &str) -> fmt::Result {
+        let content = if self.trim {
+            s.trim_end_matches([' ', '\t'])
+        } else {
+            s
+        };
+
+        if !content.is_empty() {
+            self.check_indent()?;
+
+            if !self.pending.is_empty() {
+                let pending = mem::take(&mut self.pending);
+                self.emit(&pending)?;
+            }
+
+            self.emit(content)?;
+        }
+
+        self.pending.push_str(&s[content.len()..]);
+        Ok(())
+    }
+
     /// Write the given string.
     pub fn write_str(&mut self, s: &str) -> fmt::Result {
         if s.len() > 0 {
-            self.check_indent()?;
-            self.write.write_str(s)?;
+            let mut segments = s.split('\n');
+
+            if let Some(first) = segments.next() {
+                self.write_segment(first)?;
+            }
+
+            for segment in segments {
+                self.raw_new_line()?;
+                self.write_segment(segment)?;
+            }
+
             self.current_line_empty = false;
         }
 
         Ok(())
     }
 
+    /// Write the given string exactly as-is.
+    ///
+    /// Embedded newlines are written unchanged and no indentation is applied.
+    pub fn write_verbatim(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        if !self.pending.is_empty() {
+            let pending = mem::take(&mut self.pending);
+            self.emit(&pending)?;
+        }
+
+        if self.line_hook.is_some() {
+            let mut lines = s.split('\n');
+
+            if let Some(first) = lines.next() {
+                self.line.push_str(first);
+            }
+
+            for line in lines {
+                self.end_line()?;
+                self.write.write_char('\n')?;
+                self.line.push_str(line);
+            }
+        } else {
+            self.write.write_str(s)?;
+        }
+
+        self.current_line_empty = s.ends_with('\n');
+        self.line_started = !self.current_line_empty;
+        Ok(())
+    }
+
     /// Push a new line.
     pub fn new_line(&mut self) -> fmt::Result {
-        self.write.write_char('\n')?;
+        self.raw_new_line()?;
         self.current_line_empty = true;
+        self.line_started = false;
         Ok(())
     }
 
+    /// Write out the current partial line, if output is being buffered by a line hook.
+    ///
+    /// The partial line is passed through the hook like any other line.
+    pub fn flush(&mut self) -> fmt::Result {
+        if self.line.is_empty() {
+            return Ok(());
+        }
+
+        self.end_line()
+    }
+
     /// Push a new line, unless the current line is empty.
     pub fn new_line_unless_empty(&mut self) -> fmt::Result {
         if !self.current_line_empty {
@@ -89,16 +251,70 @@ impl<'write> Formatter<'write> {
     pub fn unindent(&mut self) {
         self.indent = self.indent.saturating_sub(1);
     }
+
+    /// Current indentation level, so custom elements can match the surrounding code.
+    pub fn indentation(&self) -> usize {
+        self.indent
+    }
 }
 
 impl<'write> fmt::Write for Formatter<'write> {
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        if s.len() > 0 {
-            self.check_indent()?;
-            self.write.write_str(s)?;
-            self.current_line_empty = false;
+        Formatter::write_str(self, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Formatter;
+    use java::Java;
+    use {Element, Tokens};
+
+    #[test]
+    fn test_line_hook() {
+        let mut t = Tokens::<Java>::new();
+        t.push("class Foo {");
+        t.nested("int a;  ");
+        t.push("}");
+
+        let mut out = String::new();
+
+        {
+            let mut lines = 0;
+            let mut hook = |line: &mut String| {
+                lines += 1;
+                let trimmed = line.trim_end().len();
+                line.truncate(trimmed);
+                line.push_str(&format!(" // {}", lines));
+            };
+
+            let mut f = Formatter::with_line_hook(&mut out, &mut hook);
+
+            t.format(&mut f, &mut Default::default(), 0).unwrap();
+            f.flush().unwrap();
         }
 
-        Ok(())
+        assert_eq!("class Foo { // 1\n  int a; // 2\n} // 3", out);
+    }
+
+    #[test]
+    fn test_no_trailing_whitespace() {
+        let mut body = Tokens::<Java>::new();
+        body.push(toks!["int a;", Element::Spacing]);
+        body.push("int b; ");
+        body.push(Element::Aligned(vec![
+            (toks!["x"], toks![]),
+            (toks!["yy"], toks!["z"]),
+        ]));
+
+        let mut t = Tokens::<Java>::new();
+        t.push("class Foo {");
+        t.nested(body.join_line_spacing());
+        t.push("} ");
+
+        let out = t.to_string().unwrap();
+
+        assert_eq!("class Foo {\n  int a;\n\n  int b;\n\n  x\n  yy z\n}", out);
+        assert!(out.lines().all(|line| !line.ends_with(' ')));
     }
 }
@@ -106,6 +106,10 @@ impl Extra {
 impl<'el> Custom for Go<'el> {
     type Extra = Extra;
 
+    fn requires_trailing_semicolon() -> bool {
+        false
+    }
+
     fn format(&self, out: &mut Formatter, extra: &mut Self::Extra, level: usize) -> fmt::Result {
         use self::Go::*;
 
@@ -147,9 +151,17 @@ impl<'el> Custom for Go<'el> {
         Ok(())
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
+    fn quote_char(out: &mut Formatter, _extra: &mut Self::Extra, c: char) -> fmt::Result {
+        out.write_char('\'')?;
+        escape_char(out, c)?;
+        out.write_char('\'')
+    }
+
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
         for c in input.chars() {
             match c {
                 '\t' => out.write_str("\\t")?,
@@ -162,10 +174,13 @@ impl<'el> Custom for Go<'el> {
             };
         }
 
-        out.write_char('"')?;
         Ok(())
     }
 
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
     fn write_file<'a>(
         tokens: Tokens<'a, Self>,
         out: &mut Formatter,
@@ -242,6 +257,24 @@ pub fn interface<'a>() -> Go<'a> {
     Go::Interface
 }
 
+/// Escape a character for use in a rune literal.
+fn escape_char(out: &mut Formatter, c: char) -> fmt::Result {
+    match c {
+        '\u{0007}' => out.write_str("\\a"),
+        '\u{0008}' => out.write_str("\\b"),
+        '\u{000C}' => out.write_str("\\f"),
+        '\n' => out.write_str("\\n"),
+        '\r' => out.write_str("\\r"),
+        '\t' => out.write_str("\\t"),
+        '\u{000B}' => out.write_str("\\v"),
+        '\\' => out.write_str("\\\\"),
+        '\'' => out.write_str("\\'"),
+        c if c.is_control() && c.is_ascii() => write!(out, "\\x{:02x}", c as u32),
+        c if c.is_control() => write!(out, "\\u{:04x}", c as u32),
+        c => out.write_char(c),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{array, imported, interface, map, Extra, Go};
@@ -256,6 +289,26 @@ mod tests {
         assert_eq!(Ok("\"hello \\n world\""), res.as_ref().map(|s| s.as_str()));
     }
 
+    #[test]
+    fn test_char() {
+        let toks: Tokens<Go> = toks![
+            'a'.quoted(),
+            '"'.quoted(),
+            '\''.quoted(),
+            '\n'.quoted(),
+            '\u{0001}'.quoted(),
+            '\u{0085}'.quoted()
+        ]
+        .join_spacing();
+
+        assert_eq!(
+            Ok("'a' '\"' '\\'' '\\n' '\\x01' '\\u0085'"),
+            toks.to_string_with(Extra::from_package("foo"))
+                .as_ref()
+                .map(|s| s.as_str())
+        );
+    }
+
     #[test]
     fn test_imported() {
         let dbg = imported("foo", "Debug");
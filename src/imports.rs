@@ -0,0 +1,48 @@
+//! Options shared by languages which collect imports.
+
+use std::collections::BTreeSet;
+
+/// Order in which collected imports are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ImportOrder {
+    /// Sorted alphabetically.
+    #[default]
+    Sorted,
+    /// In the order the types are first written in the output.
+    FirstUse,
+}
+
+impl ImportOrder {
+    /// De-duplicate the given imports and put them in this order.
+    pub fn apply<T: Ord + Clone>(self, imports: Vec<T>) -> Vec<T> {
+        match self {
+            ImportOrder::Sorted => imports
+                .into_iter()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            ImportOrder::FirstUse => {
+                let mut seen = BTreeSet::new();
+                imports
+                    .into_iter()
+                    .filter(|i| seen.insert(i.clone()))
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImportOrder;
+
+    #[test]
+    fn test_apply() {
+        let imports = vec!["b", "a", "c", "a", "b"];
+        assert_eq!(
+            vec!["a", "b", "c"],
+            ImportOrder::Sorted.apply(imports.clone())
+        );
+        assert_eq!(vec!["b", "a", "c"], ImportOrder::FirstUse.apply(imports));
+    }
+}
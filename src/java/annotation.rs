@@ -0,0 +1,163 @@
+//! Data structure for annotations
+
+use cons::Cons;
+use into_tokens::IntoTokens;
+use java::Java;
+use tokens::Tokens;
+
+/// Model for Java Annotations with arguments, like `@SuppressWarnings({"unchecked", "rawtypes"})`.
+#[derive(Debug, Clone)]
+pub struct Annotation<'el> {
+    /// Type of the annotation.
+    ty: Java<'el>,
+    /// Arguments, the unnamed `value` argument has no key.
+    arguments: Vec<(Option<Cons<'el>>, Tokens<'el, Java<'el>>)>,
+}
+
+impl<'el> Annotation<'el> {
+    /// Build a new annotation without arguments.
+    pub fn new<T>(ty: T) -> Annotation<'el>
+    where
+        T: Into<Java<'el>>,
+    {
+        Annotation {
+            ty: ty.into(),
+            arguments: vec![],
+        }
+    }
+
+    /// Set the `value` argument, which is written without its name if it's the only argument.
+    pub fn value<V>(&mut self, value: V)
+    where
+        V: IntoTokens<'el, Java<'el>>,
+    {
+        self.arguments.push((None, value.into_tokens()));
+    }
+
+    /// Add a named argument, like `key = value`.
+    pub fn argument<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<Cons<'el>>,
+        V: IntoTokens<'el, Java<'el>>,
+    {
+        self.arguments.push((Some(key.into()), value.into_tokens()));
+    }
+
+    /// Set the `value` argument to an array, like `{"unchecked", "rawtypes"}`.
+    ///
+    /// An array with a single element is written without braces.
+    pub fn value_array<I>(&mut self, values: I)
+    where
+        I: IntoIterator,
+        I::Item: IntoTokens<'el, Java<'el>>,
+    {
+        self.arguments.push((None, array(values)));
+    }
+
+    /// Add a named array argument, like `key = {a, b}`.
+    ///
+    /// An array with a single element is written without braces.
+    pub fn argument_array<K, I>(&mut self, key: K, values: I)
+    where
+        K: Into<Cons<'el>>,
+        I: IntoIterator,
+        I::Item: IntoTokens<'el, Java<'el>>,
+    {
+        self.arguments.push((Some(key.into()), array(values)));
+    }
+}
+
+/// Build an array value, collapsing single elements to the braceless form.
+fn array<'el, I>(values: I) -> Tokens<'el, Java<'el>>
+where
+    I: IntoIterator,
+    I::Item: IntoTokens<'el, Java<'el>>,
+{
+    let mut values: Vec<Tokens<'el, Java<'el>>> =
+        values.into_iter().map(IntoTokens::into_tokens).collect();
+
+    if values.len() == 1 {
+        if let Some(value) = values.pop() {
+            return value;
+        }
+    }
+
+    toks!["{", values.into_tokens().join(", "), "}"]
+}
+
+into_tokens_impl_from!(Annotation<'el>, Java<'el>);
+
+impl<'el> IntoTokens<'el, Java<'el>> for Annotation<'el> {
+    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+        let mut t = toks!["@", self.ty];
+
+        if self.arguments.is_empty() {
+            return t;
+        }
+
+        let named = self.arguments.len() > 1;
+        let mut arguments = Tokens::new();
+
+        for (key, value) in self.arguments {
+            match key {
+                Some(key) => arguments.append(toks![key, " = ", value]),
+                None if named => arguments.append(toks!["value = ", value]),
+                None => arguments.append(value),
+            }
+        }
+
+        t.append("(");
+        t.append(arguments.join(", "));
+        t.append(")");
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Annotation;
+    use java::{imported, local, Java};
+    use quoted::Quoted;
+    use tokens::Tokens;
+
+    #[test]
+    fn test_value_array() {
+        let mut a = Annotation::new(local("SuppressWarnings"));
+        a.value_array(vec!["unchecked".quoted(), "rawtypes".quoted()]);
+
+        let mut single = Annotation::new(local("SuppressWarnings"));
+        single.value_array(vec!["unchecked".quoted()]);
+
+        let mut t: Tokens<Java> = Tokens::new();
+        t.push(a);
+        t.push(single);
+        assert_eq!(
+            Ok(String::from(
+                "@SuppressWarnings({\"unchecked\", \"rawtypes\"})\n@SuppressWarnings(\"unchecked\")"
+            )),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    fn test_named_arguments() {
+        let element_type = imported("java.lang.annotation", "ElementType");
+        let mut a = Annotation::new(imported("java.lang.annotation", "Target"));
+        a.value_array(vec![
+            toks![element_type.clone(), ".TYPE"],
+            toks![element_type, ".METHOD"],
+        ]);
+        a.argument("since", "\"1.0\"");
+
+        let t: Tokens<Java> = a.into();
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import java.lang.annotation.ElementType;\n",
+                "import java.lang.annotation.Target;\n",
+                "\n",
+                "@Target(value = {ElementType.TYPE, ElementType.METHOD}, since = \"1.0\")\n",
+            ))),
+            t.to_file()
+        );
+    }
+}
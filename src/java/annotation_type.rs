@@ -0,0 +1,181 @@
+//! Data structure for annotation types.
+
+use super::modifier::Modifier;
+use cons::Cons;
+use element::Element;
+use into_tokens::IntoTokens;
+use java::Java;
+use tokens::Tokens;
+
+/// Model for an element of a Java annotation type, like `String value() default "x";`.
+#[derive(Debug, Clone)]
+pub struct AnnotationElement<'el> {
+    /// Type of the element.
+    ty: Java<'el>,
+    /// Name of the element.
+    name: Cons<'el>,
+    /// Default value of the element.
+    default: Option<Tokens<'el, Java<'el>>>,
+}
+
+impl<'el> AnnotationElement<'el> {
+    /// Build a new element without a default value.
+    pub fn new<T, N>(ty: T, name: N) -> AnnotationElement<'el>
+    where
+        T: Into<Java<'el>>,
+        N: Into<Cons<'el>>,
+    {
+        AnnotationElement {
+            ty: ty.into(),
+            name: name.into(),
+            default: None,
+        }
+    }
+
+    /// Set the default value of the element.
+    pub fn default<D>(&mut self, default: D)
+    where
+        D: IntoTokens<'el, Java<'el>>,
+    {
+        self.default = Some(default.into_tokens());
+    }
+
+    /// Set the default value of the element to a quoted string.
+    pub fn default_string<S>(&mut self, default: S)
+    where
+        S: Into<Cons<'el>>,
+    {
+        self.default = Some(Element::Quoted(default.into()).into_tokens());
+    }
+}
+
+into_tokens_impl_from!(AnnotationElement<'el>, Java<'el>);
+
+impl<'el> IntoTokens<'el, Java<'el>> for AnnotationElement<'el> {
+    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+        let mut t = Tokens::new();
+
+        t.append(self.ty);
+        t.append(toks![self.name, "()"]);
+
+        if let Some(default) = self.default {
+            t.append("default");
+            t.append(default);
+        }
+
+        toks![t.join_spacing(), ";"]
+    }
+}
+
+/// Model for Java Annotation Types, declared with `@interface`.
+#[derive(Debug, Clone)]
+pub struct AnnotationType<'el> {
+    /// Annotation type modifiers.
+    pub modifiers: Vec<Modifier>,
+    /// Declared elements.
+    pub elements: Vec<AnnotationElement<'el>>,
+    /// Annotations for the annotation type, like `@Retention`.
+    annotations: Tokens<'el, Java<'el>>,
+    /// Name of annotation type.
+    name: Cons<'el>,
+}
+
+impl<'el> AnnotationType<'el> {
+    /// Build a new empty annotation type.
+    pub fn new<N>(name: N) -> AnnotationType<'el>
+    where
+        N: Into<Cons<'el>>,
+    {
+        AnnotationType {
+            modifiers: vec![Modifier::Public],
+            elements: vec![],
+            annotations: Tokens::new(),
+            name: name.into(),
+        }
+    }
+
+    /// Push an annotation.
+    pub fn annotation<A>(&mut self, annotation: A)
+    where
+        A: IntoTokens<'el, Java<'el>>,
+    {
+        self.annotations.push(annotation.into_tokens());
+    }
+
+    /// Name of annotation type.
+    pub fn name(&self) -> Cons<'el> {
+        self.name.clone()
+    }
+}
+
+into_tokens_impl_from!(AnnotationType<'el>, Java<'el>);
+
+impl<'el> IntoTokens<'el, Java<'el>> for AnnotationType<'el> {
+    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+        let mut sig = Tokens::new();
+
+        sig.extend(self.modifiers.into_tokens());
+        sig.append("@interface");
+        sig.append(self.name);
+
+        let mut s = Tokens::new();
+
+        if !self.annotations.is_empty() {
+            s.push(self.annotations);
+        }
+
+        s.push(toks![sig.join_spacing(), " {"]);
+        s.nested({
+            let mut body = Tokens::new();
+
+            for element in self.elements {
+                body.push(element);
+            }
+
+            body
+        });
+        s.push("}");
+
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnnotationElement, AnnotationType};
+    use java::{imported, local, Java, INTEGER};
+    use tokens::Tokens;
+
+    #[test]
+    fn test_annotation_type() {
+        let mut a = AnnotationType::new("Config");
+
+        let mut value = AnnotationElement::new(local("String"), "value");
+        value.default_string("x");
+        a.elements.push(value);
+
+        let mut timeout = AnnotationElement::new(INTEGER, "timeout");
+        timeout.default("30");
+        a.elements.push(timeout);
+
+        a.elements.push(AnnotationElement::new(
+            imported("java.time", "Duration"),
+            "interval",
+        ));
+
+        let t: Tokens<Java> = a.into();
+
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import java.time.Duration;\n",
+                "\n",
+                "public @interface Config {\n",
+                "  String value() default \"x\";\n",
+                "  int timeout() default 30;\n",
+                "  Duration interval();\n",
+                "}\n",
+            ))),
+            t.to_file()
+        );
+    }
+}
@@ -34,12 +34,12 @@ impl<'el> Argument<'el> {
         }
     }
 
-    /// Push an annotation.
+    /// Add an annotation, rendered inline before the type.
     pub fn annotation<A>(&mut self, annotation: A)
     where
         A: IntoTokens<'el, Java<'el>>,
     {
-        self.annotations.push(annotation.into_tokens());
+        self.annotations.append(annotation.into_tokens());
     }
 
     /// Get the variable of the argument.
@@ -67,3 +67,46 @@ impl<'el> IntoTokens<'el, Java<'el>> for Argument<'el> {
         s.join_spacing()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Argument;
+    use java::{local, Java};
+    use tokens::Tokens;
+
+    #[test]
+    fn test_final() {
+        let t: Tokens<Java> = Argument::new(local("Foo"), "foo").into();
+        assert_eq!(Ok(String::from("final Foo foo")), t.to_string());
+
+        let mut argument = Argument::new(local("Foo"), "foo");
+        argument.modifiers.clear();
+        let t: Tokens<Java> = argument.into();
+        assert_eq!(Ok(String::from("Foo foo")), t.to_string());
+    }
+
+    #[test]
+    fn test_inline_annotations() {
+        use java::{annotation, imported, Method};
+
+        let mut argument = Argument::new(local("String"), "x");
+        argument.annotation(annotation(imported("javax.annotation", "Nonnull")));
+        argument.annotation(toks!["@Size(max = 10)"]);
+
+        let mut method = Method::new("foo");
+        method.annotation(annotation(local("Override")));
+        method.arguments.push(argument);
+        method.arguments.push(Argument::new(local("int"), "y"));
+
+        let t: Tokens<Java> = method.into();
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import javax.annotation.Nonnull;\n",
+                "\n",
+                "@Override\n",
+                "public void foo(@Nonnull @Size(max = 10) final String x, final int y);\n",
+            ))),
+            t.to_file()
+        );
+    }
+}
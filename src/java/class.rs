@@ -17,6 +17,10 @@ pub struct Class<'el> {
     pub modifiers: Vec<Modifier>,
     /// Declared methods.
     pub fields: Vec<Field<'el>>,
+    /// Static initializer blocks.
+    pub static_blocks: Vec<Tokens<'el, Java<'el>>>,
+    /// Instance initializer blocks.
+    pub initializer_blocks: Vec<Tokens<'el, Java<'el>>>,
     /// Declared methods.
     pub constructors: Vec<Constructor<'el>>,
     /// Declared methods.
@@ -29,6 +33,8 @@ pub struct Class<'el> {
     pub implements: Vec<Java<'el>>,
     /// Generic parameters.
     pub parameters: Tokens<'el, Java<'el>>,
+    /// Align the names of consecutive field declarations.
+    pub align_fields: bool,
     /// Annotations for the constructor.
     annotations: Tokens<'el, Java<'el>>,
     /// Name of class.
@@ -44,12 +50,15 @@ impl<'el> Class<'el> {
         Class {
             modifiers: vec![Modifier::Public],
             fields: vec![],
+            static_blocks: vec![],
+            initializer_blocks: vec![],
             methods: vec![],
             body: Tokens::new(),
             constructors: vec![],
             extends: None,
             implements: vec![],
             parameters: Tokens::new(),
+            align_fields: false,
             annotations: Tokens::new(),
             name: name.into(),
         }
@@ -120,13 +129,42 @@ impl<'el> IntoTokens<'el, Java<'el>> for Class<'el> {
             let mut body = Tokens::new();
 
             if !self.fields.is_empty() {
-                let mut fields = Tokens::new();
+                if self.align_fields {
+                    let rows = self
+                        .fields
+                        .into_iter()
+                        .map(|field| {
+                            let (ty, name) = field.into_columns();
+                            (ty, toks![name, ";"])
+                        })
+                        .collect();
 
-                for field in self.fields {
-                    fields.push(toks![field, ";"]);
+                    body.push(Element::Aligned(rows));
+                } else {
+                    let mut fields = Tokens::new();
+
+                    for field in self.fields {
+                        fields.push(toks![field, ";"]);
+                    }
+
+                    body.push(fields);
                 }
+            }
+
+            for block in self.static_blocks {
+                let mut b = Tokens::new();
+                b.push("static {");
+                b.nested(block);
+                b.push("}");
+                body.push(b);
+            }
 
-                body.push(fields);
+            for block in self.initializer_blocks {
+                let mut b = Tokens::new();
+                b.push("{");
+                b.nested(block);
+                b.push("}");
+                body.push(b);
             }
 
             if !self.constructors.is_empty() {
@@ -154,7 +192,7 @@ impl<'el> IntoTokens<'el, Java<'el>> for Class<'el> {
 #[cfg(test)]
 mod tests {
     use super::Class;
-    use java::{local, Java};
+    use java::{imported, local, Constructor, Field, Java, INTEGER};
     use tokens::Tokens;
 
     #[test]
@@ -169,4 +207,82 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public class Foo<T> implements Super {\n}"), out);
     }
+
+    #[test]
+    fn test_static_blocks() {
+        let mut c = Class::new("Foo");
+        c.static_blocks.push(toks!["init();"]);
+        c.static_blocks.push(toks!["load();"]);
+
+        let t: Tokens<Java> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok("public class Foo {\n  static {\n    init();\n  }\n\n  static {\n    load();\n  }\n}"),
+            out
+        );
+    }
+
+    #[test]
+    fn test_initializer_blocks() {
+        let mut c = Class::new("Foo");
+        c.fields.push(Field::new(INTEGER, "foo"));
+        c.initializer_blocks.push(toks!["foo = 42;"]);
+        c.constructors.push(Constructor::new());
+
+        let t: Tokens<Java> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "public class Foo {\n",
+                "  private final int foo;\n",
+                "\n",
+                "  {\n",
+                "    foo = 42;\n",
+                "  }\n",
+                "\n",
+                "  public Foo() {\n",
+                "  }\n",
+                "}",
+            )),
+            out
+        );
+    }
+
+    #[test]
+    fn test_align_fields() {
+        let mut c = Class::new("Foo");
+        c.align_fields = true;
+        c.fields.push(Field::new(INTEGER, "foo"));
+        c.fields
+            .push(Field::new(imported("java.util", "List"), "bar"));
+
+        let mut baz = Field::new(local("String"), "baz");
+        baz.comments.push("Hello World".into());
+        baz.initializer("\"baz\"");
+        c.fields.push(baz);
+
+        let t: Tokens<Java> = c.into();
+
+        let s = t.to_file();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.List;\n",
+                "\n",
+                "public class Foo {\n",
+                "  private final int    foo;\n",
+                "  private final List   bar;\n",
+                "  /**\n",
+                "   * Hello World\n",
+                "   */\n",
+                "  private final String baz = \"baz\";\n",
+                "}\n",
+            )),
+            out
+        );
+    }
 }
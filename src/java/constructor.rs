@@ -51,15 +51,14 @@ impl<'el> IntoTokens<'el, Java<'el>> for (Cons<'el>, Constructor<'el>) {
     fn into_tokens(self) -> Tokens<'el, Java<'el>> {
         use self::Element::*;
 
-        let (name, mut c) = self;
+        let (name, c) = self;
 
         let args: Vec<Tokens<Java>> = c.arguments.into_iter().map(|a| a.into_tokens()).collect();
         let args: Tokens<Java> = args.into_tokens();
 
         let mut sig: Tokens<Java> = Tokens::new();
 
-        c.modifiers.sort();
-        sig.extend(c.modifiers.into_iter().map(Into::into));
+        sig.extend(c.modifiers.into_tokens());
 
         if !args.is_empty() {
             let sep = toks![",", PushSpacing];
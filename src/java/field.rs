@@ -65,12 +65,10 @@ impl<'el> Field<'el> {
     pub fn ty(&self) -> Java<'el> {
         self.ty.clone()
     }
-}
-
-into_tokens_impl_from!(Field<'el>, Java<'el>);
 
-impl<'el> IntoTokens<'el, Java<'el>> for Field<'el> {
-    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+    /// Split the field into the declaration up to and including its type, and the name with its
+    /// initializer.
+    pub fn into_columns(self) -> (Tokens<'el, Java<'el>>, Tokens<'el, Java<'el>>) {
         let mut tokens = Tokens::new();
 
         tokens.push_unless_empty(BlockComment(self.comments));
@@ -82,20 +80,30 @@ impl<'el> IntoTokens<'el, Java<'el>> for Field<'el> {
 
         tokens.append({
             let mut sig = Tokens::new();
-
             sig.extend(self.modifiers.into_tokens());
-
             sig.append(self.ty);
-            sig.append(self.name);
-
-            if let Some(initializer) = self.initializer {
-                sig.append("=");
-                sig.append(initializer);
-            }
-
             sig.join_spacing()
         });
 
+        let mut name = Tokens::new();
+        name.append(self.name);
+
+        if let Some(initializer) = self.initializer {
+            name.append("=");
+            name.append(initializer);
+        }
+
+        (tokens, name.join_spacing())
+    }
+}
+
+into_tokens_impl_from!(Field<'el>, Java<'el>);
+
+impl<'el> IntoTokens<'el, Java<'el>> for Field<'el> {
+    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+        let (mut tokens, name) = self.into_columns();
+        tokens.append(Element::Spacing);
+        tokens.append(name);
         tokens
     }
 }
@@ -108,7 +116,7 @@ impl<'el> From<Field<'el>> for Element<'el, Java<'el>> {
 
 #[cfg(test)]
 mod tests {
-    use java::{Field, INTEGER};
+    use java::{imported, Class, Field, INTEGER};
     use tokens::Tokens;
 
     fn field() -> Field<'static> {
@@ -133,4 +141,29 @@ mod tests {
         let t = Tokens::from(field());
         assert_eq!(Ok(String::from("private final int foo")), t.to_string());
     }
+
+    #[test]
+    fn test_imported_initializer() {
+        let list = imported("java.util", "List");
+        let array_list = imported("java.util", "ArrayList");
+
+        let mut f = Field::new(list, "foo");
+        f.initializer(toks!["new ", array_list, "<>()"]);
+
+        let mut c = Class::new("Foo");
+        c.fields.push(f);
+
+        let t: Tokens<_> = c.into();
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import java.util.ArrayList;\n",
+                "import java.util.List;\n",
+                "\n",
+                "public class Foo {\n",
+                "  private final List foo = new ArrayList<>();\n",
+                "}\n",
+            ))),
+            t.to_file()
+        );
+    }
 }
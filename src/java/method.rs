@@ -1,6 +1,6 @@
 //! Data structure for methods.
 
-use java::{Argument, BlockComment, Java, Modifier, VOID};
+use java::{override_annotation, Argument, BlockComment, Java, Modifier, VOID};
 use {Cons, IntoTokens, Tokens};
 
 /// Model for Java Methods.
@@ -14,7 +14,7 @@ pub struct Method<'el> {
     pub body: Tokens<'el, Java<'el>>,
     /// Return type.
     pub returns: Java<'el>,
-    /// Generic parameters.
+    /// Generic parameters of the method, independent of the class, like `<T extends Foo>`.
     pub parameters: Tokens<'el, Java<'el>>,
     /// Comments associated with this method.
     pub comments: Vec<Cons<'el>>,
@@ -22,6 +22,8 @@ pub struct Method<'el> {
     pub throws: Option<Tokens<'el, Java<'el>>>,
     /// Annotations for the constructor.
     annotations: Tokens<'el, Java<'el>>,
+    /// If the method is annotated with `@Override`.
+    overriding: bool,
     /// Name of the method.
     name: Cons<'el>,
 }
@@ -43,6 +45,7 @@ impl<'el> Method<'el> {
             comments: Vec::new(),
             throws: None,
             annotations: Tokens::new(),
+            overriding: false,
             name: name.into(),
         }
     }
@@ -55,6 +58,11 @@ impl<'el> Method<'el> {
         self.annotations.push(annotation.into_tokens());
     }
 
+    /// Set if the method overrides another, emitting an `@Override` annotation.
+    pub fn overriding(&mut self, overriding: bool) {
+        self.overriding = overriding;
+    }
+
     /// Name of method.
     pub fn name(&self) -> Cons<'el> {
         self.name.clone()
@@ -101,6 +109,11 @@ impl<'el> IntoTokens<'el, Java<'el>> for Method<'el> {
         let mut s = Tokens::new();
 
         s.push_unless_empty(BlockComment(self.comments));
+
+        if self.overriding {
+            s.push(override_annotation());
+        }
+
         s.push_unless_empty(self.annotations);
 
         let sig = sig.join_spacing();
@@ -120,6 +133,7 @@ impl<'el> IntoTokens<'el, Java<'el>> for Method<'el> {
 #[cfg(test)]
 mod tests {
     use super::Method;
+    use java::{imported, local, Argument, Modifier};
     use tokens::Tokens;
 
     fn build_method() -> Method<'static> {
@@ -158,4 +172,63 @@ mod tests {
             t.to_string()
         );
     }
+
+    #[test]
+    fn test_overriding() {
+        let mut c = Method::new("toString");
+        c.overriding(true);
+        c.annotation(toks!["@Nonnull"]);
+        c.returns = local("String");
+        c.body.push("return \"Foo\";");
+
+        let t = Tokens::from(c);
+        assert_eq!(
+            Ok(String::from(
+                "@Override\n@Nonnull\npublic String toString() {\n  return \"Foo\";\n}",
+            )),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    fn test_package_private() {
+        let mut c = Method::new("foo");
+        c.modifiers = vec![Modifier::Static];
+        let t = Tokens::from(c);
+        assert_eq!(Ok(String::from("static void foo();")), t.to_string());
+
+        let mut c = Method::new("foo");
+        c.modifiers = vec![];
+        let t = Tokens::from(c);
+        assert_eq!(Ok(String::from("void foo();")), t.to_string());
+    }
+
+    #[test]
+    fn test_bounded_type_parameters() {
+        let comparable = imported("java.lang", "Comparable");
+        let list = imported("java.util", "List");
+
+        let mut c = Method::new("max");
+        c.modifiers.push(Modifier::Static);
+        c.parameters.append(toks![
+            "T extends ",
+            comparable.with_arguments(vec![local("T")])
+        ]);
+        c.parameters.append("U");
+        c.returns = local("T");
+        c.arguments.push(Argument::new(
+            list.with_arguments(vec![local("T")]),
+            "items",
+        ));
+
+        let t = Tokens::from(c);
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import java.util.List;\n",
+                "\n",
+                "public static <T extends Comparable<T>, U> T max(final List<T> items);\n",
+            ))),
+            t.to_file()
+        );
+    }
 }
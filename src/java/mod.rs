@@ -1,5 +1,7 @@
 //! Specialization for Java code generation.
 
+mod annotation;
+mod annotation_type;
 mod argument;
 mod class;
 mod constructor;
@@ -8,8 +10,12 @@ mod field;
 mod interface;
 mod method;
 mod modifier;
+mod object_methods;
+mod switch;
 mod utils;
 
+pub use self::annotation::Annotation;
+pub use self::annotation_type::{AnnotationElement, AnnotationType};
 pub use self::argument::Argument;
 pub use self::class::Class;
 pub use self::constructor::Constructor;
@@ -18,15 +24,20 @@ pub use self::field::Field;
 pub use self::interface::Interface;
 pub use self::method::Method;
 pub use self::modifier::Modifier;
+pub use self::object_methods::object_methods;
+pub use self::switch::{record_pattern, switch, Binding, Case, SwitchStyle};
 pub use self::utils::BlockComment;
 
 use super::cons::Cons;
 use super::custom::Custom;
+use super::element::Element;
 use super::formatter::Formatter;
+use super::imports::ImportOrder;
 use super::into_tokens::IntoTokens;
 use super::tokens::Tokens;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
+use std::iter;
 
 static JAVA_LANG: &'static str = "java.lang";
 static SEP: &'static str = ".";
@@ -107,6 +118,24 @@ pub struct Optional<'el> {
     pub field: Box<Java<'el>>,
 }
 
+/// A type with a type-use annotation, like `@Nullable String`.
+#[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub struct Annotated<'el> {
+    /// The type of the annotation.
+    pub annotation: Box<Java<'el>>,
+    /// The type that is annotated.
+    pub value: Box<Java<'el>>,
+}
+
+/// The kind of bound on a wildcard.
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub enum WildcardKind {
+    /// An upper bound, `? extends T`.
+    Extends,
+    /// A lower bound, `? super T`.
+    Super,
+}
+
 /// Java token specialization.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub enum Java<'el> {
@@ -126,6 +155,20 @@ pub enum Java<'el> {
     },
     /// Optional type.
     Optional(Optional<'el>),
+    /// A type with a type-use annotation, like `@Nullable String`.
+    Annotated(Annotated<'el>),
+    /// An array type, like `int[]` or `String[][]`.
+    Array {
+        /// The type of the array elements.
+        inner: Box<Java<'el>>,
+        /// Number of dimensions of the array.
+        dimensions: usize,
+    },
+    /// A wildcard type argument, like `?` or `? extends T`.
+    Wildcard {
+        /// The bound of the wildcard, if any.
+        bound: Option<(WildcardKind, Box<Java<'el>>)>,
+    },
 }
 
 into_tokens_impl_from!(Java<'el>, Java<'el>);
@@ -138,7 +181,16 @@ pub struct Extra<'el> {
     pub package: Option<Cons<'el>>,
 
     /// Types which has been imported into the local namespace.
-    imported: HashMap<String, String>,
+    imported: BTreeMap<String, String>,
+
+    /// Escape all non-ASCII characters in quoted strings.
+    ascii_only: bool,
+
+    /// Order of the imports.
+    import_order: ImportOrder,
+
+    /// Group of the imports, by package.
+    import_groups: Option<fn(&str) -> usize>,
 }
 
 impl<'el> Extra<'el> {
@@ -149,7 +201,10 @@ impl<'el> Extra<'el> {
     {
         Extra {
             package: Some(package.into()),
-            imported: HashMap::new(),
+            imported: BTreeMap::new(),
+            ascii_only: false,
+            import_order: ImportOrder::Sorted,
+            import_groups: None,
         }
     }
 
@@ -160,6 +215,28 @@ impl<'el> Extra<'el> {
     {
         self.package = Some(package.into())
     }
+
+    /// Escape any character above `0x7F` in quoted strings as `\uXXXX`.
+    ///
+    /// Characters outside of the basic multilingual plane are escaped as surrogate pairs.
+    pub fn ascii_only(&mut self, ascii_only: bool) {
+        self.ascii_only = ascii_only;
+    }
+
+    /// Set the order of the imports, which are sorted by default.
+    ///
+    /// When two imported types share a name, the first one in this order is imported.
+    pub fn import_order(&mut self, import_order: ImportOrder) {
+        self.import_order = import_order;
+    }
+
+    /// Partition the imports into blocks separated by an empty line.
+    ///
+    /// The function maps the package of each import to its group, and groups are written in
+    /// ascending order. The import order is kept within each group.
+    pub fn import_groups(&mut self, group: fn(&str) -> usize) {
+        self.import_groups = Some(group);
+    }
 }
 
 impl<'el> Java<'el> {
@@ -185,23 +262,41 @@ impl<'el> Java<'el> {
         }
     }
 
-    fn type_imports<'a>(java: &'a Java<'a>, modules: &mut BTreeSet<(&'a str, &'a str)>) {
+    fn type_imports<'a>(java: &'a Java<'a>, modules: &mut Vec<(&'a str, &'a str)>) {
         use self::Java::*;
 
         match *java {
             Class(ref class) => {
+                modules.push((class.package.as_ref(), class.name.as_ref()));
+
                 for argument in &class.arguments {
                     Self::type_imports(argument, modules);
                 }
-
-                modules.insert((class.package.as_ref(), class.name.as_ref()));
+            }
+            Array { ref inner, .. } => {
+                Self::type_imports(inner, modules);
+            }
+            Wildcard {
+                bound: Some((_, ref bound)),
+            } => {
+                Self::type_imports(bound, modules);
+            }
+            Optional(self::Optional { ref field, .. }) => {
+                Self::type_imports(field, modules);
+            }
+            Annotated(self::Annotated {
+                ref annotation,
+                ref value,
+            }) => {
+                Self::type_imports(annotation, modules);
+                Self::type_imports(value, modules);
             }
             _ => {}
         };
     }
 
     fn imports<'a>(tokens: &'a Tokens<'a, Self>, extra: &mut Extra) -> Option<Tokens<'a, Self>> {
-        let mut modules = BTreeSet::new();
+        let mut modules = Vec::new();
 
         let file_package = extra.package.as_ref().map(|p| p.as_ref());
 
@@ -213,9 +308,9 @@ impl<'el> Java<'el> {
             return None;
         }
 
-        let mut out = Tokens::new();
+        let mut imports = Vec::new();
 
-        for (package, name) in modules {
+        for (package, name) in extra.import_order.apply(modules) {
             if extra.imported.contains_key(name) {
                 continue;
             }
@@ -228,10 +323,25 @@ impl<'el> Java<'el> {
                 continue;
             }
 
-            out.push(toks!("import ", package, SEP, name, ";"));
+            let group = extra.import_groups.map_or(0, |group| group(package));
+            imports.push((group, toks!("import ", package, SEP, name, ";")));
             extra.imported.insert(name.to_string(), package.to_string());
         }
 
+        imports.sort_by_key(|&(group, _)| group);
+
+        let mut out = Tokens::new();
+        let mut last = Option::None;
+
+        for (group, import) in imports {
+            if last.is_some() && last != Some(group) {
+                out.append(Element::LineSpacing);
+            }
+
+            out.push(import);
+            last = Some(group);
+        }
+
         Some(out)
     }
 
@@ -252,6 +362,25 @@ impl<'el> Java<'el> {
         }
     }
 
+    /// Get an array of the type, adding a dimension if it already is an array.
+    pub fn as_array(&self) -> Java<'el> {
+        use self::Java::*;
+
+        match *self {
+            Array {
+                ref inner,
+                dimensions,
+            } => Array {
+                inner: inner.clone(),
+                dimensions: dimensions + 1,
+            },
+            ref java => Array {
+                inner: Box::new(java.clone()),
+                dimensions: 1,
+            },
+        }
+    }
+
     /// Get the raw type.
     ///
     /// A raw type is one without generic arguments.
@@ -308,6 +437,16 @@ impl<'el> Java<'el> {
                         .zip(r.arguments.iter())
                         .all(|(l, r)| l.equals(r))
             }
+            (
+                &Array {
+                    inner: ref l_inner,
+                    dimensions: l_dimensions,
+                },
+                &Array {
+                    inner: ref r_inner,
+                    dimensions: r_dimensions,
+                },
+            ) => l_dimensions == r_dimensions && l_inner.equals(r_inner),
             _ => false,
         }
     }
@@ -321,6 +460,9 @@ impl<'el> Java<'el> {
             Class(ref cls) => cls.name.clone(),
             Local { ref name, .. } => name.clone(),
             Optional(self::Optional { ref value, .. }) => value.name(),
+            Annotated(self::Annotated { ref value, .. }) => value.name(),
+            Array { ref inner, .. } => inner.name(),
+            Wildcard { .. } => Cons::Borrowed("?"),
         }
     }
 
@@ -333,6 +475,9 @@ impl<'el> Java<'el> {
             Class(ref cls) => Some(cls.package.clone()),
             Local { .. } => None,
             Optional(self::Optional { ref value, .. }) => value.package(),
+            Annotated(self::Annotated { ref value, .. }) => value.package(),
+            Array { ref inner, .. } => inner.package(),
+            Wildcard { .. } => None,
         }
     }
 
@@ -343,6 +488,7 @@ impl<'el> Java<'el> {
         match *self {
             Class(ref cls) => Some(&cls.arguments),
             Optional(self::Optional { ref value, .. }) => value.arguments(),
+            Annotated(self::Annotated { ref value, .. }) => value.arguments(),
             _ => None,
         }
     }
@@ -396,6 +542,77 @@ impl<'el> Java<'el> {
     pub fn is_generic(&self) -> bool {
         self.arguments().map(|a| !a.is_empty()).unwrap_or(false)
     }
+
+    /// Format a type-use annotation, followed by a space.
+    fn format_annotation(
+        annotation: &Java<'el>,
+        out: &mut Formatter,
+        extra: &mut Extra<'el>,
+        level: usize,
+    ) -> fmt::Result {
+        out.write_str("@")?;
+        annotation.format(out, extra, level)?;
+        out.write_str(" ")
+    }
+
+    /// Format a class, with an optional type-use annotation.
+    ///
+    /// The annotation is placed right before the simple name, like `java.util.@Nullable List`,
+    /// since Java doesn't allow it before a qualified name.
+    fn format_class(
+        cls: &Type<'el>,
+        annotation: Option<&Java<'el>>,
+        out: &mut Formatter,
+        extra: &mut Extra<'el>,
+        level: usize,
+    ) -> fmt::Result {
+        {
+            let file_package = extra.package.as_ref().map(|p| p.as_ref());
+            let imported = extra.imported.get(cls.name.as_ref()).map(String::as_str);
+            let pkg = Some(cls.package.as_ref());
+
+            if cls.package.as_ref() != JAVA_LANG && imported != pkg && file_package != pkg {
+                out.write_str(cls.package.as_ref())?;
+                out.write_str(SEP)?;
+            }
+        }
+
+        {
+            let mut names = iter::once(&cls.name).chain(cls.path.iter()).peekable();
+
+            while let Some(n) = names.next() {
+                if names.peek().is_none() {
+                    if let Some(annotation) = annotation {
+                        Self::format_annotation(annotation, out, extra, level)?;
+                    }
+                }
+
+                out.write_str(n.as_ref())?;
+
+                if names.peek().is_some() {
+                    out.write_str(".")?;
+                }
+            }
+        }
+
+        if !cls.arguments.is_empty() {
+            out.write_str("<")?;
+
+            let mut it = cls.arguments.iter().peekable();
+
+            while let Some(argument) = it.next() {
+                argument.format(out, extra, level + 1usize)?;
+
+                if it.peek().is_some() {
+                    out.write_str(", ")?;
+                }
+            }
+
+            out.write_str(">")?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'el> Custom for Java<'el> {
@@ -417,75 +634,88 @@ impl<'el> Custom for Java<'el> {
                 }
             }
             Class(ref cls) => {
-                {
-                    let file_package = extra.package.as_ref().map(|p| p.as_ref());
-                    let imported = extra.imported.get(cls.name.as_ref()).map(String::as_str);
-                    let pkg = Some(cls.package.as_ref());
-
-                    if cls.package.as_ref() != JAVA_LANG && imported != pkg && file_package != pkg {
-                        out.write_str(cls.package.as_ref())?;
-                        out.write_str(SEP)?;
-                    }
+                Self::format_class(cls, Option::None, out, extra, level)?;
+            }
+            Local { ref name } => {
+                out.write_str(name.as_ref())?;
+            }
+            Optional(self::Optional { ref field, .. }) => {
+                field.format(out, extra, level)?;
+            }
+            Annotated(self::Annotated {
+                ref annotation,
+                ref value,
+            }) => match **value {
+                Class(ref cls) => {
+                    Self::format_class(cls, Some(annotation), out, extra, level)?;
                 }
-
-                {
-                    out.write_str(cls.name.as_ref())?;
-
-                    let mut it = cls.path.iter();
-
-                    while let Some(n) = it.next() {
-                        out.write_str(".")?;
-                        out.write_str(n.as_ref())?;
+                Array {
+                    ref inner,
+                    dimensions,
+                } => {
+                    inner.format(out, extra, 0usize)?;
+                    out.write_str(" ")?;
+                    Self::format_annotation(annotation, out, extra, level)?;
+
+                    for _ in 0..dimensions {
+                        out.write_str("[]")?;
                     }
                 }
+                ref value => {
+                    Self::format_annotation(annotation, out, extra, level)?;
+                    value.format(out, extra, level)?;
+                }
+            },
+            Array {
+                ref inner,
+                dimensions,
+            } => {
+                // array elements are never boxed, even when used as generic arguments.
+                inner.format(out, extra, 0usize)?;
 
-                if !cls.arguments.is_empty() {
-                    out.write_str("<")?;
-
-                    let mut it = cls.arguments.iter().peekable();
-
-                    while let Some(argument) = it.next() {
-                        argument.format(out, extra, level + 1usize)?;
+                for _ in 0..dimensions {
+                    out.write_str("[]")?;
+                }
+            }
+            Wildcard { ref bound } => {
+                out.write_str("?")?;
 
-                        if it.peek().is_some() {
-                            out.write_str(", ")?;
-                        }
+                if let Some((kind, ref bound)) = *bound {
+                    match kind {
+                        WildcardKind::Extends => out.write_str(" extends ")?,
+                        WildcardKind::Super => out.write_str(" super ")?,
                     }
 
-                    out.write_str(">")?;
+                    bound.format(out, extra, level)?;
                 }
             }
-            Local { ref name } => {
-                out.write_str(name.as_ref())?;
-            }
-            Optional(self::Optional { ref field, .. }) => {
-                field.format(out, extra, level)?;
-            }
         }
 
         Ok(())
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
-
-        for c in input.chars() {
-            match c {
-                '\t' => out.write_str("\\t")?,
-                '\u{0007}' => out.write_str("\\b")?,
-                '\n' => out.write_str("\\n")?,
-                '\r' => out.write_str("\\r")?,
-                '\u{0014}' => out.write_str("\\f")?,
-                '\'' => out.write_str("\\'")?,
-                '"' => out.write_str("\\\"")?,
-                '\\' => out.write_str("\\\\")?,
-                c => out.write_char(c)?,
-            }
-        }
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
-        out.write_char('"')?;
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
+        escape(out, input, false)
+    }
 
-        Ok(())
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
+    fn quote_string_with(out: &mut Formatter, extra: &mut Self::Extra, input: &str) -> fmt::Result {
+        Self::write_quoted_open(out)?;
+        escape(out, input, extra.ascii_only)?;
+        Self::write_quoted_close(out)
+    }
+
+    fn quote_char(out: &mut Formatter, extra: &mut Self::Extra, c: char) -> fmt::Result {
+        out.write_char('\'')?;
+        escape(out, c.encode_utf8(&mut [0u8; 4]), extra.ascii_only)?;
+        out.write_char('\'')
     }
 
     fn write_file<'a>(
@@ -509,6 +739,31 @@ impl<'el> Custom for Java<'el> {
     }
 }
 
+/// Escape the content of a string, optionally escaping everything that isn't ASCII.
+fn escape(out: &mut Formatter, input: &str, ascii_only: bool) -> fmt::Result {
+    for c in input.chars() {
+        match c {
+            '\t' => out.write_str("\\t")?,
+            '\u{0008}' => out.write_str("\\b")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\u{000C}' => out.write_str("\\f")?,
+            '\'' => out.write_str("\\'")?,
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32)?,
+            c if ascii_only && !c.is_ascii() => {
+                for unit in c.encode_utf16(&mut [0u16; 2]) {
+                    write!(out, "\\u{:04x}", unit)?;
+                }
+            }
+            c => out.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
+
 /// Setup an imported element.
 pub fn imported<'a, P: Into<Cons<'a>>, N: Into<Cons<'a>>>(package: P, name: N) -> Java<'a> {
     Java::Class(Type {
@@ -519,6 +774,55 @@ pub fn imported<'a, P: Into<Cons<'a>>, N: Into<Cons<'a>>>(package: P, name: N) -
     })
 }
 
+/// Setup an imported element from a fully qualified name, like `java.util.Map`.
+///
+/// Nested classes may be separated with either `$` or `.`, like `java.util.Map$Entry` or
+/// `java.util.Map.Entry`. The first component starting with an uppercase letter is taken as the
+/// name of the class. If no component does, the last one is used.
+///
+/// Returns `None` if the name has no package component, or contains empty components.
+pub fn imported_fqn<'a>(fqn: &'a str) -> Option<Java<'a>> {
+    let parts: Vec<&'a str> = fqn.split(&['.', '$'][..]).collect();
+
+    let index = parts
+        .iter()
+        .position(|p| p.chars().next().map(char::is_uppercase).unwrap_or(false))
+        .unwrap_or(parts.len() - 1);
+
+    if index == 0 || parts.iter().any(|p| p.is_empty()) {
+        return None;
+    }
+
+    let offset = parts[..index].iter().map(|p| p.len() + 1).sum::<usize>();
+    let package = &fqn[..offset - 1];
+
+    Some(Java::Class(Type {
+        package: package.into(),
+        name: parts[index].into(),
+        path: parts[index + 1..].iter().map(|p| Cons::from(*p)).collect(),
+        arguments: vec![],
+    }))
+}
+
+/// Setup an unbounded wildcard, `?`.
+pub fn wildcard<'el>() -> Java<'el> {
+    Java::Wildcard { bound: None }
+}
+
+/// Setup a wildcard with an upper bound, `? extends T`.
+pub fn wildcard_extends<'el, B: Into<Java<'el>>>(bound: B) -> Java<'el> {
+    Java::Wildcard {
+        bound: Some((WildcardKind::Extends, Box::new(bound.into()))),
+    }
+}
+
+/// Setup a wildcard with a lower bound, `? super T`.
+pub fn wildcard_super<'el, B: Into<Java<'el>>>(bound: B) -> Java<'el> {
+    Java::Wildcard {
+        bound: Some((WildcardKind::Super, Box::new(bound.into()))),
+    }
+}
+
 /// Setup a local element from borrowed components.
 pub fn local<'el, N: Into<Cons<'el>>>(name: N) -> Java<'el> {
     Java::Local { name: name.into() }
@@ -532,6 +836,247 @@ pub fn optional<'el, I: Into<Java<'el>>, F: Into<Java<'el>>>(value: I, field: F)
     })
 }
 
+/// Setup a type annotated with a type-use annotation, like `@Nullable String`.
+///
+/// Annotating an array annotates the array type itself, like `String @Nullable []`. To annotate
+/// the element type instead, annotate it before making the array, like `@Nullable String[]`.
+pub fn annotated<'el, A: Into<Java<'el>>, V: Into<Java<'el>>>(
+    annotation: A,
+    value: V,
+) -> Java<'el> {
+    Java::Annotated(Annotated {
+        annotation: Box::new(annotation.into()),
+        value: Box::new(value.into()),
+    })
+}
+
+/// Setup a marker annotation, like `@Override`, for the given type.
+pub fn annotation<'el, A: Into<Java<'el>>>(ty: A) -> Tokens<'el, Java<'el>> {
+    toks!["@", ty.into()]
+}
+
+/// The `@Override` annotation.
+pub fn override_annotation<'el>() -> Tokens<'el, Java<'el>> {
+    annotation(imported(JAVA_LANG, "Override"))
+}
+
+/// The `@FunctionalInterface` annotation.
+pub fn functional_interface<'el>() -> Tokens<'el, Java<'el>> {
+    annotation(imported(JAVA_LANG, "FunctionalInterface"))
+}
+
+/// The `@Deprecated` annotation.
+pub fn deprecated<'el>() -> Tokens<'el, Java<'el>> {
+    annotation(imported(JAVA_LANG, "Deprecated"))
+}
+
+/// Setup a local variable declaration statement, like `final Type name = init;`.
+pub fn local_var<'el, T, N, I>(is_final: bool, ty: T, name: N, init: I) -> Tokens<'el, Java<'el>>
+where
+    T: Into<Java<'el>>,
+    N: Into<Cons<'el>>,
+    I: IntoTokens<'el, Java<'el>>,
+{
+    let mut t = Tokens::new();
+
+    if is_final {
+        t.append("final");
+    }
+
+    t.append(ty.into());
+    t.append(name.into());
+    t.append("=");
+    t.append(init.into_tokens());
+
+    toks![t.join_spacing(), ";"]
+}
+
+/// Setup a local variable declaration statement with an inferred type, like `var name = init;`.
+pub fn var<'el, N, I>(name: N, init: I) -> Tokens<'el, Java<'el>>
+where
+    N: Into<Cons<'el>>,
+    I: IntoTokens<'el, Java<'el>>,
+{
+    toks!["var ", name.into(), " = ", init.into_tokens(), ";"]
+}
+
+/// Setup a text block literal, like `"""` followed by the content on its own lines.
+///
+/// Content lines are indented one level deeper than the surrounding code. Backslashes and `"""`
+/// sequences are escaped, as is the last trailing space of a line so it isn't stripped.
+pub fn text_block<'el, C: AsRef<str>>(content: C) -> Tokens<'el, Java<'el>> {
+    let content = content.as_ref();
+    let closed_on_own_line = content.is_empty() || content.ends_with('\n');
+    let mut lines = content.lines().peekable();
+    let mut t = Tokens::new();
+
+    t.append("\"\"\"");
+    t.append(Element::Indent);
+
+    while let Some(line) = lines.next() {
+        let mut line = line.replace('\\', "\\\\").replace("\"\"\"", "\\\"\"\"");
+
+        if line.ends_with(' ') {
+            line.pop();
+            line.push_str("\\s");
+        }
+
+        // a quote right before the closing delimiter would end the block early.
+        if lines.peek().is_none() && !closed_on_own_line && line.ends_with('"') {
+            line.pop();
+            line.push_str("\\\"");
+        }
+
+        t.append(Element::Line);
+        t.append(line);
+    }
+
+    if closed_on_own_line {
+        t.append(Element::Line);
+    }
+
+    t.append("\"\"\"");
+    t.append(Element::Unindent);
+    t
+}
+
+/// Setup a `synchronized (lock) { ... }` block.
+pub fn synchronized_block<'el, L, B>(lock: L, body: B) -> Tokens<'el, Java<'el>>
+where
+    L: IntoTokens<'el, Java<'el>>,
+    B: IntoTokens<'el, Java<'el>>,
+{
+    let mut t = Tokens::new();
+    t.push(toks!["synchronized (", lock.into_tokens(), ") {"]);
+    t.nested(body.into_tokens());
+    t.push("}");
+    t
+}
+
+/// Setup a pattern matching `instanceof` expression, like `o instanceof String s`.
+pub fn instance_of<'el, E, T, N>(expr: E, ty: T, name: N) -> Tokens<'el, Java<'el>>
+where
+    E: IntoTokens<'el, Java<'el>>,
+    T: Into<Java<'el>>,
+    N: Into<Cons<'el>>,
+{
+    toks![
+        expr.into_tokens(),
+        " instanceof ",
+        ty.into(),
+        " ",
+        name.into()
+    ]
+}
+
+/// Setup an `assert` statement, like `assert cond : "message";`.
+///
+/// A message given as a plain string is quoted, any other tokens are written as an expression.
+pub fn assert_stmt<'el, C, M>(condition: C, message: Option<M>) -> Tokens<'el, Java<'el>>
+where
+    C: IntoTokens<'el, Java<'el>>,
+    M: Into<Element<'el, Java<'el>>>,
+{
+    let mut t = toks!["assert ", condition.into_tokens()];
+
+    if let Some(message) = message {
+        let message = match message.into() {
+            Element::Literal(literal) => Element::Quoted(literal),
+            other => other,
+        };
+
+        t.append(" : ");
+        t.append(message);
+    }
+
+    t.append(";");
+    t
+}
+
+/// Setup an assignment to a field, like `this.field = value;`.
+///
+/// Without `qualify` the field is assigned as `field = value;`. To assign a parameter which
+/// shadows the field, use [`assign_param`].
+pub fn assign_field<'el, F, V>(field: F, value: V, qualify: bool) -> Tokens<'el, Java<'el>>
+where
+    F: Into<Cons<'el>>,
+    V: IntoTokens<'el, Java<'el>>,
+{
+    let mut t = Tokens::new();
+
+    if qualify {
+        t.append("this.");
+    }
+
+    t.append(toks![field.into(), " = ", value.into_tokens(), ";"]);
+    t
+}
+
+/// Setup an assignment of a parameter to the field it shadows, like `this.field = field;`.
+pub fn assign_param<'el, F>(field: F) -> Tokens<'el, Java<'el>>
+where
+    F: Into<Cons<'el>>,
+{
+    let field = field.into();
+    toks!["this.", field.clone(), " = ", field, ";"]
+}
+
+/// Setup a chain of method calls, like `builder.setA(1).build()`.
+pub fn chain<'el, R, I, M, A>(receiver: R, calls: I) -> Tokens<'el, Java<'el>>
+where
+    R: IntoTokens<'el, Java<'el>>,
+    I: IntoIterator<Item = (M, A)>,
+    M: Into<Cons<'el>>,
+    A: IntoTokens<'el, Java<'el>>,
+{
+    let mut t = receiver.into_tokens();
+
+    for (method, args) in calls {
+        t.append(toks![".", method.into(), "(", args.into_tokens(), ")"]);
+    }
+
+    t
+}
+
+/// Setup a chain of method calls like [`chain`], with each call on its own indented line.
+pub fn chain_lines<'el, R, I, M, A>(receiver: R, calls: I) -> Tokens<'el, Java<'el>>
+where
+    R: IntoTokens<'el, Java<'el>>,
+    I: IntoIterator<Item = (M, A)>,
+    M: Into<Cons<'el>>,
+    A: IntoTokens<'el, Java<'el>>,
+{
+    let mut t = receiver.into_tokens();
+    t.append(Element::Indent);
+
+    for (method, args) in calls {
+        t.push(toks![".", method.into(), "(", args.into_tokens(), ")"]);
+    }
+
+    t.append(Element::Unindent);
+    t
+}
+
+/// Setup a `try (...) { ... }` block with the given resource declarations, separated by `;`.
+pub fn try_with_resources<'el, R, B>(resources: R, body: B) -> Tokens<'el, Java<'el>>
+where
+    R: IntoIterator,
+    R::Item: IntoTokens<'el, Java<'el>>,
+    B: IntoTokens<'el, Java<'el>>,
+{
+    let resources: Tokens<'el, Java<'el>> = resources
+        .into_iter()
+        .map(IntoTokens::into_tokens)
+        .collect::<Vec<_>>()
+        .into_tokens();
+
+    let mut t = Tokens::new();
+    t.push(toks!["try (", resources.join("; "), ") {"]);
+    t.nested(body.into_tokens());
+    t.push("}");
+    t
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -559,6 +1104,137 @@ mod tests {
         assert_eq!("\"hello \\n world\"", toks.to_string().unwrap().as_str());
     }
 
+    #[test]
+    fn test_form_feed() {
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.append("\u{000C}".quoted());
+        toks.append("\u{0014}".quoted());
+        toks.append("\u{0008}".quoted());
+        toks.append("\u{0007}".quoted());
+        let toks = toks.join_spacing();
+        assert_eq!(
+            "\"\\f\" \"\\u0014\" \"\\b\" \"\\u0007\"",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_ascii_only() {
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.append("h\u{e9}llo \u{1F60A}".quoted());
+
+        assert_eq!(
+            Ok("\"h\u{e9}llo \u{1F60A}\""),
+            toks.clone().to_string().as_ref().map(|s| s.as_str())
+        );
+
+        let mut extra = Extra::default();
+        extra.ascii_only(true);
+        assert_eq!(
+            Ok("\"h\\u00e9llo \\ud83d\\ude0a\""),
+            toks.to_string_with(extra).as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_imported_fqn() {
+        assert_eq!(
+            Some(imported("java.util", "Map")),
+            imported_fqn("java.util.Map")
+        );
+        assert_eq!(
+            Some(imported("java.util", "Map").path("Entry")),
+            imported_fqn("java.util.Map$Entry")
+        );
+        assert_eq!(
+            Some(imported("java.util", "Map").path("Entry")),
+            imported_fqn("java.util.Map.Entry")
+        );
+        assert_eq!(Some(imported("foo", "bar")), imported_fqn("foo.bar"));
+        assert_eq!(None, imported_fqn("Map"));
+        assert_eq!(None, imported_fqn("java..Map"));
+    }
+
+    #[test]
+    fn test_annotations() {
+        let nullable = annotation(imported("javax.annotation", "Nullable"));
+        let toks = toks!(
+            override_annotation(),
+            functional_interface(),
+            deprecated(),
+            nullable
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok("import javax.annotation.Nullable;\n\n@Override @FunctionalInterface @Deprecated @Nullable\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_nested_generics() {
+        let map = imported("java.util", "Map");
+        let list = imported("java.util", "List");
+        let set = imported("java.util", "Set");
+
+        let inner = map.with_arguments(vec![INTEGER, set.with_arguments(vec![LONG.as_array()])]);
+        let ty = map.with_arguments(vec![local("String"), list.with_arguments(vec![inner])]);
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.List;\n",
+                "import java.util.Map;\n",
+                "import java.util.Set;\n",
+                "\n",
+                "Map<String, List<Map<Integer, Set<long[]>>>>\n",
+            )),
+            toks!(ty).to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        let a = imported("java.io", "A").as_array();
+        let list = imported("java.util", "List").with_arguments(vec![INTEGER.as_array()]);
+
+        assert!(!INTEGER.as_array().is_primitive());
+        assert_eq!(a, a.as_boxed());
+        assert!(a
+            .as_array()
+            .equals(&imported("java.io", "A").as_array().as_array()));
+
+        let toks = toks!(a.as_array(), INTEGER.as_array(), list).join_spacing();
+
+        assert_eq!(
+            Ok("import java.io.A;\nimport java.util.List;\n\nA[][] int[] List<int[]>\n",),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let list = imported("java.util", "List");
+        let number = imported("java.math", "BigDecimal");
+
+        let toks = toks!(
+            list.with_arguments(vec![wildcard()]),
+            list.with_arguments(vec![wildcard_extends(number)]),
+            list.with_arguments(vec![wildcard_super(INTEGER)])
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.math.BigDecimal;\n",
+                "import java.util.List;\n",
+                "\n",
+                "List<?> List<? extends BigDecimal> List<? super Integer>\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
     #[test]
     fn test_imported() {
         let integer = imported("java.lang", "Integer");
@@ -574,4 +1250,478 @@ mod tests {
             toks.to_file().as_ref().map(|s| s.as_str())
         );
     }
+
+    #[test]
+    fn test_imports_deduplicated_across_members() {
+        let foo = imported("com.example", "Foo");
+        let error = imported("com.example", "FooException");
+        let list = imported("java.util", "List");
+        let optional_foo = optional(
+            foo.clone(),
+            imported("java.util", "Optional").with_arguments(vec![foo.clone()]),
+        );
+
+        let mut field = Field::new(list.with_arguments(vec![foo.clone()]), "foos");
+        field.annotation(annotation(imported("com.example", "Nullable")));
+
+        let mut method = Method::new("get");
+        method.annotation(annotation(imported("com.example", "Nullable")));
+        method.arguments.push(Argument::new(foo.clone(), "foo"));
+        method.arguments.push(Argument::new(optional_foo, "other"));
+        method.returns = list.with_arguments(vec![foo.clone()]);
+        method.throws = Some(toks![error.with_arguments(vec![foo.clone()])]);
+
+        let mut class = Class::new("Bar");
+        class.fields.push(field);
+        class.methods.push(method);
+        class.annotation(annotation(imported("com.example", "Nullable")));
+
+        let file = Tokens::from(class).to_file().unwrap();
+
+        assert_eq!(
+            concat!(
+                "import com.example.Foo;\n",
+                "import com.example.FooException;\n",
+                "import com.example.Nullable;\n",
+                "import java.util.List;\n",
+                "import java.util.Optional;\n",
+            ),
+            &file[..file.find("\n\n").unwrap() + 1]
+        );
+    }
+
+    #[test]
+    fn test_try_with_resources() {
+        let reader = imported("java.io", "BufferedReader");
+        let writer = imported("java.io", "Writer");
+
+        let toks = try_with_resources(
+            vec![
+                toks![reader.clone(), " r = new ", reader, "(in)"],
+                toks![writer, " w = open()"],
+            ],
+            toks!["w.write(r.readLine());"],
+        );
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.io.BufferedReader;\n",
+                "import java.io.Writer;\n",
+                "\n",
+                "try (BufferedReader r = new BufferedReader(in); Writer w = open()) {\n",
+                "  w.write(r.readLine());\n",
+                "}\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_local_var() {
+        let list = imported("java.util", "List");
+        let toks = toks!(
+            local_var(true, list.clone(), "a", toks!["new ", list.clone(), "()"]),
+            local_var(false, INTEGER, "b", "42")
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok("import java.util.List;\n\nfinal List a = new List(); int b = 42;\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_imports_collisions_deterministic() {
+        let build = || {
+            let names = ["List", "Date", "Entry"];
+            let packages = ["java.util", "java.sql", "com.example", "java.awt"];
+            let mut toks: Tokens<Java> = Tokens::new();
+
+            for package in packages.iter().rev() {
+                for name in names.iter() {
+                    toks.append(imported(*package, *name));
+                }
+            }
+
+            toks.join_spacing().to_file().unwrap()
+        };
+
+        let first = build();
+
+        assert!(first.starts_with(concat!(
+            "import com.example.Date;\n",
+            "import com.example.Entry;\n",
+            "import com.example.List;\n",
+            "\n",
+        )));
+
+        for _ in 0..8 {
+            assert_eq!(first, build());
+        }
+    }
+
+    #[test]
+    fn test_char() {
+        let toks: Tokens<Java> = toks!(
+            'a'.quoted(),
+            '\''.quoted(),
+            '\\'.quoted(),
+            '\n'.quoted(),
+            '\u{0001}'.quoted()
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok("'a' '\\'' '\\\\' '\\n' '\\u0001'"),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_var() {
+        let list = imported("java.util", "ArrayList");
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(var("a", toks!["new ", list, "<>()"]));
+        toks.push(var("b", "42"));
+
+        assert_eq!(
+            Ok("import java.util.ArrayList;\n\nvar a = new ArrayList<>();\nvar b = 42;\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_synchronized_block() {
+        let registry = imported("com.example", "Registry");
+        let toks: Tokens<Java> = synchronized_block(toks![registry, ".class"], "count += 1;");
+
+        assert_eq!(
+            Ok(concat!(
+                "import com.example.Registry;\n",
+                "\n",
+                "synchronized (Registry.class) {\n",
+                "  count += 1;\n",
+                "}\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_assert_stmt() {
+        let objects = imported("java.util", "Objects");
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(assert_stmt(toks![&objects, ".nonNull(a)"], None::<&str>));
+        toks.push(assert_stmt("b > 0", Some("b must be \"positive\"")));
+        toks.push(assert_stmt("c", Some(toks!["\"c: \" + ", "c"])));
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.Objects;\n",
+                "\n",
+                "assert Objects.nonNull(a);\n",
+                "assert b > 0 : \"b must be \\\"positive\\\"\";\n",
+                "assert c : \"c: \" + c;\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_assign_field() {
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(assign_param("name"));
+        toks.push(assign_field("count", "0", false));
+        toks.push(assign_field("count", "other.count", true));
+        toks.push(assign_field(
+            "items",
+            toks![imported("java.util", "List"), ".of()"],
+            false,
+        ));
+
+        assert_eq!(
+            Ok(concat!(
+                "this.name = name;\n",
+                "count = 0;\n",
+                "this.count = other.count;\n",
+                "items = java.util.List.of();",
+            )),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_instance_of() {
+        let list = imported("java.util", "List");
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["if (", instance_of("o", list, "l"), ") {"]);
+        toks.nested("return l.size();");
+        toks.push("}");
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.List;\n",
+                "\n",
+                "if (o instanceof List l) {\n",
+                "  return l.size();\n",
+                "}\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_text_block() {
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks![
+            "String a = ",
+            text_block("{\n  \"key\": \"a\\b\"\n}\n"),
+            ";"
+        ]);
+        toks.push(toks!["String b = ", text_block("say \"\"\"hi\"\"\" "), ";"]);
+        toks.push(toks!["String c = ", text_block("quoted \"x\""), ";"]);
+
+        assert_eq!(
+            Ok(concat!(
+                "String a = \"\"\"\n",
+                "  {\n",
+                "    \"key\": \"a\\\\b\"\n",
+                "  }\n",
+                "  \"\"\";\n",
+                "String b = \"\"\"\n",
+                "  say \\\"\"\"hi\\\"\"\"\\s\"\"\";\n",
+                "String c = \"\"\"\n",
+                "  quoted \"x\\\"\"\"\";",
+            )),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_chain() {
+        let duration = imported("java.time", "Duration");
+        let calls = vec![
+            ("setA", toks!["1"]),
+            ("setTimeout", toks![duration, ".ofSeconds(5)"]),
+            ("build", toks![]),
+        ];
+
+        let toks = toks![chain("builder", calls.clone()), ";"];
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.time.Duration;\n",
+                "\n",
+                "builder.setA(1).setTimeout(Duration.ofSeconds(5)).build();\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["Foo foo = ", chain_lines("builder", calls)]);
+        toks.append(";");
+
+        assert_eq!(
+            Ok(concat!(
+                "Foo foo = builder\n",
+                "  .setA(1)\n",
+                "  .setTimeout(java.time.Duration.ofSeconds(5))\n",
+                "  .build();",
+            )),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_import_order() {
+        let toks = toks!(
+            imported("java.util", "List"),
+            imported("java.io", "File"),
+            imported("java.util", "List"),
+            imported("com.example", "Foo")
+        )
+        .join_spacing();
+
+        let mut extra = Extra::default();
+        extra.import_order(ImportOrder::FirstUse);
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.List;\n",
+                "import java.io.File;\n",
+                "import com.example.Foo;\n",
+                "\n",
+                "List File List Foo\n",
+            )),
+            toks.clone()
+                .to_file_with(extra)
+                .as_ref()
+                .map(|s| s.as_str())
+        );
+
+        assert_eq!(
+            Ok(concat!(
+                "import com.example.Foo;\n",
+                "import java.io.File;\n",
+                "import java.util.List;\n",
+                "\n",
+                "List File List Foo\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_import_order_nested() {
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push("class Foo {");
+        toks.nested(toks![imported("a.b", "Inner"), " inner = null;"]);
+        toks.push("}");
+        toks.push(toks![
+            imported("c.d", "Outer").with_arguments(vec![imported("e.f", "Argument")]),
+            " outer;"
+        ]);
+
+        let mut extra = Extra::default();
+        extra.import_order(ImportOrder::FirstUse);
+
+        assert_eq!(
+            Ok(concat!(
+                "import a.b.Inner;\n",
+                "import c.d.Outer;\n",
+                "import e.f.Argument;\n",
+                "\n",
+                "class Foo {\n",
+                "  Inner inner = null;\n",
+                "}\n",
+                "Outer<Argument> outer;\n",
+            )),
+            toks.to_file_with(extra).as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_import_groups() {
+        let toks = toks!(
+            imported("com.example", "Foo"),
+            imported("javax.inject", "Inject"),
+            imported("java.util", "Map"),
+            imported("java.io", "File")
+        )
+        .join_spacing();
+
+        let mut extra = Extra::default();
+        extra.import_groups(|package| {
+            if package.starts_with("java.") {
+                0
+            } else if package.starts_with("javax.") {
+                1
+            } else {
+                2
+            }
+        });
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.io.File;\n",
+                "import java.util.Map;\n",
+                "\n",
+                "import javax.inject.Inject;\n",
+                "\n",
+                "import com.example.Foo;\n",
+                "\n",
+                "Foo Inject Map File\n",
+            )),
+            toks.to_file_with(extra).as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_annotated() {
+        let nullable = imported("javax.annotation", "Nullable");
+        let nonnull = imported("javax.annotation", "Nonnull");
+        let list = imported("java.util", "List");
+
+        let toks: Tokens<Java> = toks!(
+            annotated(nullable.clone(), imported(JAVA_LANG, "String")),
+            annotated(
+                nonnull,
+                list.with_arguments(vec![annotated(nullable, INTEGER)])
+            )
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.List;\n",
+                "import javax.annotation.Nonnull;\n",
+                "import javax.annotation.Nullable;\n",
+                "\n",
+                "@Nullable String @Nonnull List<@Nullable Integer>\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_annotated_qualified() {
+        let nullable = imported("javax.annotation", "Nullable");
+        let list = imported("java.util", "List");
+        let other_list = imported("com.example", "List");
+        let entry = imported("java.util", "Map").path("Entry");
+
+        let toks: Tokens<Java> = toks!(
+            annotated(nullable.clone(), list.clone()),
+            annotated(nullable.clone(), entry),
+            annotated(nullable.clone(), local("T"))
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "java.util.@javax.annotation.Nullable List ",
+                "java.util.Map.@javax.annotation.Nullable Entry ",
+                "@javax.annotation.Nullable T",
+            )),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+
+        let toks: Tokens<Java> = toks!(
+            annotated(nullable.clone(), other_list),
+            annotated(nullable.clone(), list)
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "import com.example.List;\n",
+                "import javax.annotation.Nullable;\n",
+                "\n",
+                "@Nullable List java.util.@Nullable List\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_annotated_array() {
+        let nullable = imported("javax.annotation", "Nullable");
+        let string = imported(JAVA_LANG, "String");
+
+        let toks: Tokens<Java> = toks!(
+            annotated(nullable.clone(), string.as_array()),
+            annotated(nullable, string).as_array()
+        )
+        .join_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "import javax.annotation.Nullable;\n",
+                "\n",
+                "String @Nullable [] @Nullable String[]\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
 }
@@ -1,9 +1,10 @@
 //! Individual java modifier
 
-use std::collections::BTreeSet;
-use {Custom, Element, IntoTokens, Tokens};
+use {Custom, Element, IntoTokens, ModifierOrder, Tokens};
 
 /// A Java modifier.
+///
+/// Package-private access has no keyword, and is expressed by leaving out any access modifier.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
 pub enum Modifier {
     /// default
@@ -22,6 +23,8 @@ pub enum Modifier {
     Final,
     /// Native
     Native,
+    /// synchronized
+    Synchronized,
 }
 
 impl Modifier {
@@ -38,6 +41,25 @@ impl Modifier {
             Static => "static",
             Final => "final",
             Native => "native",
+            Synchronized => "synchronized",
+        }
+    }
+}
+
+impl ModifierOrder for Modifier {
+    fn rank(&self) -> usize {
+        use self::Modifier::*;
+
+        match *self {
+            Public => 0,
+            Protected => 1,
+            Private => 2,
+            Abstract => 3,
+            Default => 4,
+            Static => 5,
+            Final => 6,
+            Synchronized => 7,
+            Native => 8,
         }
     }
 }
@@ -48,10 +70,12 @@ impl<'el, C: Custom> From<Modifier> for Element<'el, C> {
     }
 }
 
+/// Modifiers are de-duplicated and written in the canonical order.
+///
+/// For a different order, sort them with [`ModifierOrder::sort_by`] and append them one by one.
 impl<'el, C: Custom> IntoTokens<'el, C> for Vec<Modifier> {
     fn into_tokens(self) -> Tokens<'el, C> {
-        self.into_iter()
-            .collect::<BTreeSet<_>>()
+        Modifier::sort_by(self, Modifier::canonical_cmp)
             .into_iter()
             .map(Element::from)
             .collect()
@@ -61,8 +85,10 @@ impl<'el, C: Custom> IntoTokens<'el, C> for Vec<Modifier> {
 #[cfg(test)]
 mod tests {
     use super::Modifier;
+    use into_tokens::IntoTokens;
     use java::Java;
     use tokens::Tokens;
+    use {Element, ModifierOrder};
 
     #[test]
     fn test_vec() {
@@ -72,4 +98,67 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public static final"), out);
     }
+
+    #[test]
+    fn test_canonical_order() {
+        use self::Modifier::*;
+        let el: Tokens<Java> = vec![Final, Default, Static, Public, Final]
+            .into_tokens()
+            .join_spacing();
+        let s = el.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("public default static final"), out);
+    }
+
+    #[test]
+    fn test_protected_order() {
+        use self::Modifier::*;
+        let el: Tokens<Java> = vec![Final, Static, Protected, Abstract]
+            .into_tokens()
+            .join_spacing();
+        let s = el.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("protected abstract static final"), out);
+    }
+
+    #[test]
+    fn test_synchronized_order() {
+        use self::Modifier::*;
+        let el: Tokens<Java> = vec![Synchronized, Final, Public]
+            .into_tokens()
+            .join_spacing();
+        let s = el.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("public final synchronized"), out);
+    }
+
+    #[test]
+    fn test_sort_by() {
+        use self::Modifier::*;
+        let sorted = Modifier::sort_by(vec![Static, Final, Public, Static], |a, b| {
+            Modifier::canonical_cmp(a, b).reverse()
+        });
+        assert_eq!(vec![Final, Static, Public], sorted);
+    }
+
+    #[test]
+    fn test_modifier_order() {
+        use self::Modifier::*;
+        let modifiers = vec![Final, Public, Static];
+
+        let custom: Tokens<Java> = Modifier::sort_by(modifiers.clone(), |a, b| {
+            Modifier::canonical_cmp(a, b).reverse()
+        })
+        .into_iter()
+        .map(Element::from)
+        .collect();
+        let s = toks![custom.join_spacing(), " int a;"].to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("final static public int a;"), out);
+
+        let el: Tokens<Java> = toks![modifiers.into_tokens().join_spacing(), " int a;"];
+        let s = el.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("public static final int a;"), out);
+    }
 }
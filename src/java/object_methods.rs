@@ -0,0 +1,200 @@
+//! Builders for the standard `Object` methods.
+
+use java::{imported, local, Argument, Field, Java, Method, BOOLEAN, INTEGER};
+use {Cons, Quoted, Tokens};
+
+/// Build `equals`, `hashCode` and `toString` methods, in that order, over the given fields.
+///
+/// Primitives are compared with `==`, except floating point ones which use `compare`. Arrays
+/// use the helpers in `java.util.Arrays`, and any other type uses `java.util.Objects`.
+pub fn object_methods<'el, N>(class_name: N, fields: &[Field<'el>]) -> Vec<Method<'el>>
+where
+    N: Into<Cons<'el>>,
+{
+    let class_name = class_name.into();
+    vec![
+        equals(class_name.clone(), fields),
+        hash_code(fields),
+        to_string(class_name, fields),
+    ]
+}
+
+fn equals<'el>(class_name: Cons<'el>, fields: &[Field<'el>]) -> Method<'el> {
+    let objects = imported("java.util", "Objects");
+    let arrays = imported("java.util", "Arrays");
+
+    let mut m = Method::new("equals");
+    m.overriding(true);
+    m.returns = BOOLEAN;
+    m.arguments.push(Argument::new(local("Object"), "o"));
+
+    m.body.push("if (this == o) {");
+    m.body.nested("return true;");
+    m.body.push("}");
+    m.body
+        .push(toks!["if (!(o instanceof ", class_name.clone(), ")) {"]);
+    m.body.nested("return false;");
+    m.body.push("}");
+
+    if fields.is_empty() {
+        m.body.push("return true;");
+        return m;
+    }
+
+    m.body.push(toks![
+        "final ",
+        class_name.clone(),
+        " other = (",
+        class_name,
+        ") o;"
+    ]);
+
+    let mut compares: Tokens<'el, Java<'el>> = Tokens::new();
+
+    for field in fields {
+        let name = field.var();
+        let this = toks!["this.", name.clone()];
+        let other = toks!["other.", name];
+
+        compares.append(match field.ty() {
+            Java::Primitive { primitive, boxed }
+                if primitive == "float" || primitive == "double" =>
+            {
+                toks![boxed, ".compare(", this, ", ", other, ") == 0"]
+            }
+            Java::Primitive { .. } => toks![this, " == ", other],
+            Java::Array { .. } => toks![arrays.clone(), ".equals(", this, ", ", other, ")"],
+            _ => toks![objects.clone(), ".equals(", this, ", ", other, ")"],
+        });
+    }
+
+    m.body.push(toks!["return ", compares.join(" && "), ";"]);
+    m
+}
+
+fn hash_code<'el>(fields: &[Field<'el>]) -> Method<'el> {
+    let arrays = imported("java.util", "Arrays");
+
+    let mut m = Method::new("hashCode");
+    m.overriding(true);
+    m.returns = INTEGER;
+
+    let mut values: Tokens<'el, Java<'el>> = Tokens::new();
+
+    for field in fields {
+        values.append(match field.ty() {
+            Java::Array { .. } => toks![arrays.clone(), ".hashCode(", field.var(), ")"],
+            _ => toks![field.var()],
+        });
+    }
+
+    m.body.push(toks![
+        "return ",
+        imported("java.util", "Objects"),
+        ".hash(",
+        values.join(", "),
+        ");"
+    ]);
+    m
+}
+
+fn to_string<'el>(class_name: Cons<'el>, fields: &[Field<'el>]) -> Method<'el> {
+    let arrays = imported("java.util", "Arrays");
+
+    let mut m = Method::new("toString");
+    m.overriding(true);
+    m.returns = local("String");
+
+    let mut parts: Tokens<'el, Java<'el>> = Tokens::new();
+    parts.append(format!("{}{{", class_name.as_str()).quoted());
+
+    for (i, field) in fields.iter().enumerate() {
+        let separator = if i == 0 { "" } else { ", " };
+        parts.append(format!("{}{}=", separator, field.var().as_str()).quoted());
+
+        parts.append(match field.ty() {
+            Java::Array { .. } => toks![arrays.clone(), ".toString(", field.var(), ")"],
+            _ => toks![field.var()],
+        });
+    }
+
+    parts.append("}".quoted());
+
+    m.body.push(toks!["return ", parts.join(" + "), ";"]);
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::object_methods;
+    use java::{local, Field, Java, DOUBLE, INTEGER};
+    use tokens::Tokens;
+
+    #[test]
+    fn test_object_methods() {
+        let fields = vec![
+            Field::new(INTEGER, "id"),
+            Field::new(DOUBLE, "score"),
+            Field::new(local("String"), "name"),
+            Field::new(INTEGER.as_array(), "tags"),
+        ];
+
+        let mut t: Tokens<Java> = Tokens::new();
+
+        for method in object_methods("Foo", &fields) {
+            t.push(method);
+        }
+
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import java.util.Arrays;\n",
+                "import java.util.Objects;\n",
+                "\n",
+                "@Override\n",
+                "public boolean equals(final Object o) {\n",
+                "  if (this == o) {\n",
+                "    return true;\n",
+                "  }\n",
+                "  if (!(o instanceof Foo)) {\n",
+                "    return false;\n",
+                "  }\n",
+                "  final Foo other = (Foo) o;\n",
+                "  return this.id == other.id && Double.compare(this.score, other.score) == 0 \
+                 && Objects.equals(this.name, other.name) && Arrays.equals(this.tags, other.tags);\n",
+                "}\n",
+                "@Override\n",
+                "public int hashCode() {\n",
+                "  return Objects.hash(id, score, name, Arrays.hashCode(tags));\n",
+                "}\n",
+                "@Override\n",
+                "public String toString() {\n",
+                "  return \"Foo{\" + \"id=\" + id + \", score=\" + score + \", name=\" + name \
+                 + \", tags=\" + Arrays.toString(tags) + \"}\";\n",
+                "}\n",
+            ))),
+            t.to_file()
+        );
+    }
+
+    #[test]
+    fn test_no_fields() {
+        let methods = object_methods("Empty", &[]);
+        let t: Tokens<Java> = methods[0].clone().into();
+
+        assert_eq!(
+            Ok(String::from(concat!(
+                "@Override\n",
+                "public boolean equals(final Object o) {\n",
+                "  if (this == o) {\n",
+                "    return true;\n",
+                "  }\n",
+                "  if (!(o instanceof Empty)) {\n",
+                "    return false;\n",
+                "  }\n",
+                "  return true;\n",
+                "}",
+            ))),
+            t.to_string()
+        );
+    }
+}
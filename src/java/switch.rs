@@ -0,0 +1,285 @@
+//! Data structure for switch statements and expressions.
+
+use java::Java;
+use {Cons, IntoTokens, Tokens};
+
+/// Form of a switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchStyle {
+    /// Traditional labels, `case A:`.
+    Colon,
+    /// Arrow labels of switch expressions, `case A -> ...`.
+    Arrow,
+}
+
+/// A binding of a record pattern, like `int x` in `Point(int x, int y)`.
+#[derive(Debug, Clone)]
+pub enum Binding<'el> {
+    /// Bind a component to a variable of the given type, which may be `var`.
+    Var(Java<'el>, Cons<'el>),
+    /// Deconstruct a component which is itself a record, binding its components to variables.
+    Record(Java<'el>, Vec<(Java<'el>, Cons<'el>)>),
+}
+
+into_tokens_impl_from!(Binding<'el>, Java<'el>);
+
+impl<'el> IntoTokens<'el, Java<'el>> for Binding<'el> {
+    fn into_tokens(self) -> Tokens<'el, Java<'el>> {
+        match self {
+            Binding::Var(ty, name) => toks![ty, " ", name],
+            Binding::Record(ty, bindings) => record_pattern(
+                ty,
+                bindings
+                    .into_iter()
+                    .map(|(ty, name)| Binding::Var(ty, name)),
+            ),
+        }
+    }
+}
+
+/// Setup a record deconstruction pattern, like `Point(int x, int y)`.
+pub fn record_pattern<'el, T, I>(ty: T, bindings: I) -> Tokens<'el, Java<'el>>
+where
+    T: Into<Java<'el>>,
+    I: IntoIterator<Item = Binding<'el>>,
+{
+    let bindings: Tokens<'el, Java<'el>> = bindings
+        .into_iter()
+        .map(IntoTokens::into_tokens)
+        .collect::<Vec<_>>()
+        .into_tokens();
+
+    toks![ty.into(), "(", bindings.join(", "), ")"]
+}
+
+/// A case of a switch.
+#[derive(Debug, Clone)]
+pub struct Case<'el> {
+    /// Labels matched by the case, the `default` case if empty.
+    pub labels: Vec<Tokens<'el, Java<'el>>>,
+    /// Statements of the case.
+    pub body: Tokens<'el, Java<'el>>,
+    /// Value yielded by the case, when used in a switch expression.
+    pub yields: Option<Tokens<'el, Java<'el>>>,
+}
+
+impl<'el> Case<'el> {
+    /// Build a new case matching the given label.
+    pub fn new<L>(label: L) -> Case<'el>
+    where
+        L: IntoTokens<'el, Java<'el>>,
+    {
+        Case {
+            labels: vec![label.into_tokens()],
+            body: Tokens::new(),
+            yields: None,
+        }
+    }
+
+    /// Build a new case matching a record deconstruction pattern, like `case Point(int x, int y)`.
+    pub fn record<T, I>(ty: T, bindings: I) -> Case<'el>
+    where
+        T: Into<Java<'el>>,
+        I: IntoIterator<Item = Binding<'el>>,
+    {
+        Case::new(record_pattern(ty, bindings))
+    }
+
+    /// Build the `default` case.
+    pub fn default_case() -> Case<'el> {
+        Case {
+            labels: vec![],
+            body: Tokens::new(),
+            yields: None,
+        }
+    }
+
+    fn into_tokens_with(self, style: SwitchStyle) -> Tokens<'el, Java<'el>> {
+        let label = if self.labels.is_empty() {
+            toks!["default"]
+        } else {
+            let labels: Tokens<'el, Java<'el>> = self.labels.into_tokens();
+            toks!["case ", labels.join(", ")]
+        };
+
+        let mut t = Tokens::new();
+
+        match style {
+            SwitchStyle::Colon => {
+                t.push(toks![label, ":"]);
+
+                let mut body = self.body;
+
+                if let Some(yields) = self.yields {
+                    body.push(toks!["yield ", yields, ";"]);
+                }
+
+                t.nested(body);
+            }
+            SwitchStyle::Arrow => match self.yields {
+                Some(yields) if self.body.is_empty() => {
+                    t.push(toks![label, " -> ", yields, ";"]);
+                }
+                yields => {
+                    t.push(toks![label, " -> {"]);
+
+                    let mut body = self.body;
+
+                    if let Some(yields) = yields {
+                        body.push(toks!["yield ", yields, ";"]);
+                    }
+
+                    t.nested(body);
+                    t.push("}");
+                }
+            },
+        }
+
+        t
+    }
+}
+
+/// Setup a switch over the given expression, with the given form of case labels.
+///
+/// When used as an expression, the terminating `;` must be added by the caller.
+pub fn switch<'el, E, I>(expr: E, style: SwitchStyle, cases: I) -> Tokens<'el, Java<'el>>
+where
+    E: IntoTokens<'el, Java<'el>>,
+    I: IntoIterator<Item = Case<'el>>,
+{
+    let mut t = Tokens::new();
+    t.append(toks!["switch (", expr.into_tokens(), ") {"]);
+
+    let mut body = Tokens::new();
+
+    for case in cases {
+        body.append(case.into_tokens_with(style));
+    }
+
+    t.nested(body);
+    t.push("}");
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{switch, Binding, Case, SwitchStyle};
+    use java::{imported, local, Java, INTEGER};
+    use tokens::Tokens;
+
+    fn cases() -> Vec<Case<'static>> {
+        let mut a = Case::new("A");
+        a.labels.push(toks!["B"]);
+        a.yields = Some(toks!["1"]);
+
+        let mut c = Case::new("C");
+        c.body.push(toks![
+            imported("java.util", "Objects"),
+            ".requireNonNull(x);"
+        ]);
+        c.yields = Some(toks!["2"]);
+
+        let mut d = Case::default_case();
+        d.body.push("throw new IllegalStateException();");
+
+        vec![a, c, d]
+    }
+
+    #[test]
+    fn test_arrow() {
+        let mut t: Tokens<Java> = Tokens::new();
+        t.push(toks![
+            "int v = ",
+            switch("x", SwitchStyle::Arrow, cases()),
+            ";"
+        ]);
+
+        assert_eq!(
+            Ok(concat!(
+                "import java.util.Objects;\n",
+                "\n",
+                "int v = switch (x) {\n",
+                "  case A, B -> 1;\n",
+                "  case C -> {\n",
+                "    Objects.requireNonNull(x);\n",
+                "    yield 2;\n",
+                "  }\n",
+                "  default -> {\n",
+                "    throw new IllegalStateException();\n",
+                "  }\n",
+                "};\n",
+            )),
+            t.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_colon() {
+        let t: Tokens<Java> = switch("x", SwitchStyle::Colon, cases());
+
+        assert_eq!(
+            Ok(concat!(
+                "switch (x) {\n",
+                "  case A, B:\n",
+                "    yield 1;\n",
+                "  case C:\n",
+                "    java.util.Objects.requireNonNull(x);\n",
+                "    yield 2;\n",
+                "  default:\n",
+                "    throw new IllegalStateException();\n",
+                "}",
+            )),
+            t.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_record_patterns() {
+        let point = imported("com.example", "Point");
+        let line = imported("com.example", "Line");
+
+        let mut a = Case::record(
+            point.clone(),
+            vec![
+                Binding::Var(INTEGER, "x".into()),
+                Binding::Var(local("var"), "y".into()),
+            ],
+        );
+        a.yields = Some(toks!["x + y"]);
+
+        let mut b = Case::record(
+            line,
+            vec![
+                Binding::Record(
+                    point.clone(),
+                    vec![(INTEGER, "x1".into()), (INTEGER, "y1".into())],
+                ),
+                Binding::Var(point, "end".into()),
+            ],
+        );
+        b.yields = Some(toks!["x1 + y1"]);
+
+        let mut d = Case::default_case();
+        d.yields = Some(toks!["0"]);
+
+        let toks = toks![
+            "int sum = ",
+            switch("shape", SwitchStyle::Arrow, vec![a, b, d]),
+            ";"
+        ];
+
+        assert_eq!(
+            Ok(concat!(
+                "import com.example.Line;\n",
+                "import com.example.Point;\n",
+                "\n",
+                "int sum = switch (shape) {\n",
+                "  case Point(int x, var y) -> x + y;\n",
+                "  case Line(Point(int x1, int y1), Point end) -> x1 + y1;\n",
+                "  default -> 0;\n",
+                "};\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+}
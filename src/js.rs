@@ -2,7 +2,7 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{self, Write};
-use {Cons, Custom, Formatter, IntoTokens, Quoted, Tokens};
+use {Cons, Custom, Formatter, IntoTokens, QuoteStyle, Quoted, Tokens};
 
 static SEP: &'static str = ".";
 static PATH_SEP: &'static str = "/";
@@ -99,33 +99,46 @@ impl<'el> JavaScript<'el> {
     }
 }
 
+/// Extra data for formatting.
+#[derive(Debug, Default)]
+pub struct Extra {
+    /// Delimiter of quoted strings.
+    quote_style: QuoteStyle,
+}
+
+impl Extra {
+    /// Set the delimiter of quoted strings.
+    ///
+    /// Only the chosen delimiter is escaped inside of the string.
+    pub fn quote_style(&mut self, quote_style: QuoteStyle) {
+        self.quote_style = quote_style;
+    }
+}
+
 impl<'el> Custom for JavaScript<'el> {
-    type Extra = ();
+    type Extra = Extra;
 
     fn format(&self, out: &mut Formatter, _extra: &mut Self::Extra, _level: usize) -> fmt::Result {
         write!(out, "{}", self)
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
-
-        for c in input.chars() {
-            match c {
-                '\t' => out.write_str("\\t")?,
-                '\u{0007}' => out.write_str("\\b")?,
-                '\n' => out.write_str("\\n")?,
-                '\r' => out.write_str("\\r")?,
-                '\u{0014}' => out.write_str("\\f")?,
-                '\'' => out.write_str("\\'")?,
-                '"' => out.write_str("\\\"")?,
-                '\\' => out.write_str("\\\\")?,
-                c => out.write_char(c)?,
-            };
-        }
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
-        out.write_char('"')?;
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
+        QuoteStyle::Double.escape(out, input)
+    }
 
-        Ok(())
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
+    fn quote_string_with(out: &mut Formatter, extra: &mut Self::Extra, input: &str) -> fmt::Result {
+        let delimiter = extra.quote_style.delimiter();
+        out.write_char(delimiter)?;
+        extra.quote_style.escape(out, input)?;
+        out.write_char(delimiter)
     }
 
     fn write_file<'a>(
@@ -172,9 +185,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{imported, local, JavaScript};
+    use super::{imported, local, Extra, JavaScript};
     use quoted::Quoted;
     use tokens::Tokens;
+    use QuoteStyle;
 
     #[test]
     fn test_function() {
@@ -201,6 +215,15 @@ mod tests {
         assert_eq!(Ok(String::from("\"hello \\n world\"")), toks.to_string());
     }
 
+    #[test]
+    fn test_control_escapes() {
+        let toks: Tokens<JavaScript> = toks!["\u{0007}\u{0008}\u{000C}\u{0014}".quoted()];
+        assert_eq!(
+            "\"\u{0007}\\b\\f\u{0014}\"",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
     #[test]
     fn test_imported() {
         let mut toks: Tokens<JavaScript> = Tokens::new();
@@ -221,4 +244,20 @@ mod tests {
 
         assert_eq!(Ok("vec\n"), toks.to_file().as_ref().map(|s| s.as_str()));
     }
+
+    #[test]
+    fn test_quote_style() {
+        let toks: Tokens<JavaScript> = toks!["it's \"quoted\"".quoted()];
+        assert_eq!(
+            "\"it's \\\"quoted\\\"\"",
+            toks.clone().to_string().unwrap().as_str()
+        );
+
+        let mut extra = Extra::default();
+        extra.quote_style(QuoteStyle::Single);
+        assert_eq!(
+            "'it\\'s \"quoted\"'",
+            toks.to_string_with(extra).unwrap().as_str()
+        );
+    }
 }
@@ -9,33 +9,43 @@ pub mod csharp;
 mod custom;
 pub mod dart;
 mod element;
+mod file_writer;
 mod formatter;
 pub mod go;
+mod imports;
 mod into_tokens;
 pub mod java;
 pub mod js;
+mod modifier_order;
+pub mod naming;
 pub mod python;
 mod quoted;
 pub mod rust;
 pub mod swift;
 mod tokens;
+mod visit;
+mod wrap;
 mod write_tokens;
 
-pub use self::cons::Cons;
+pub use self::cons::{Cons, Interner};
 pub use self::csharp::Csharp;
 pub use self::custom::Custom;
 pub use self::dart::Dart;
 pub use self::element::Element;
+pub use self::file_writer::FileWriter;
 pub use self::formatter::{Formatter, IoFmt};
 pub use self::go::Go;
+pub use self::imports::ImportOrder;
 pub use self::into_tokens::IntoTokens;
 pub use self::java::Java;
 pub use self::js::JavaScript;
+pub use self::modifier_order::ModifierOrder;
 pub use self::python::Python;
-pub use self::quoted::Quoted;
+pub use self::quoted::{QuoteStyle, Quoted};
 pub use self::rust::Rust;
 pub use self::tokens::Tokens;
-pub use self::write_tokens::WriteTokens;
+pub use self::visit::ElementVisitor;
+pub use self::write_tokens::{WriteTokens, WriteTokensIo};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,33 @@
+//! Ordering shared by the modifiers of languages.
+
+use std::cmp::Ordering;
+
+/// Modifiers which have a conventional declaration order, like `public static final`.
+pub trait ModifierOrder: PartialEq + Sized {
+    /// Position of the modifier in the conventional declaration order.
+    fn rank(&self) -> usize;
+
+    /// Compare two modifiers by their conventional declaration order.
+    fn canonical_cmp(a: &Self, b: &Self) -> Ordering {
+        a.rank().cmp(&b.rank())
+    }
+
+    /// De-duplicate the given modifiers and sort them with the given comparator.
+    ///
+    /// Use this together with a custom comparator if the canonical order doesn't match your style.
+    fn sort_by<F>(modifiers: Vec<Self>, compare: F) -> Vec<Self>
+    where
+        F: FnMut(&Self, &Self) -> Ordering,
+    {
+        let mut out = Vec::with_capacity(modifiers.len());
+
+        for modifier in modifiers {
+            if !out.contains(&modifier) {
+                out.push(modifier);
+            }
+        }
+
+        out.sort_by(compare);
+        out
+    }
+}
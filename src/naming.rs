@@ -0,0 +1,163 @@
+//! Conversions between naming conventions, for building identifiers.
+//!
+//! Words are split on non-alphanumeric characters and on changes of case. A run of capitals is
+//! kept as one word, so `HTTPServer` is made up of `HTTP` and `Server`. Digits belong to the
+//! word they follow.
+
+/// Convert to `camelCase`, like `HTTPServer` to `httpServer`.
+pub fn to_camel_case<S: AsRef<str>>(input: S) -> String {
+    let mut out = String::new();
+
+    for (i, word) in words(input.as_ref()).into_iter().enumerate() {
+        if i == 0 {
+            out.push_str(&word.to_lowercase());
+        } else {
+            capitalize_into(&mut out, word);
+        }
+    }
+
+    out
+}
+
+/// Convert to `PascalCase`, like `http_server` to `HttpServer`.
+pub fn to_pascal_case<S: AsRef<str>>(input: S) -> String {
+    let mut out = String::new();
+
+    for word in words(input.as_ref()) {
+        capitalize_into(&mut out, word);
+    }
+
+    out
+}
+
+/// Convert to `snake_case`, like `HTTPServer` to `http_server`.
+pub fn to_snake_case<S: AsRef<str>>(input: S) -> String {
+    words(input.as_ref())
+        .into_iter()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Convert to `SCREAMING_SNAKE_CASE`, like `httpServer` to `HTTP_SERVER`.
+pub fn to_screaming_snake_case<S: AsRef<str>>(input: S) -> String {
+    words(input.as_ref())
+        .into_iter()
+        .map(str::to_uppercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Write the word with its first character in upper case, and the rest in lower case.
+fn capitalize_into(out: &mut String, word: &str) {
+    let mut chars = word.chars();
+
+    if let Some(first) = chars.next() {
+        out.extend(first.to_uppercase());
+        out.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+/// Split the input into words.
+fn words(input: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for part in input.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+
+        for (n, &(i, c)) in chars.iter().enumerate().skip(1) {
+            let prev = chars[n - 1].1;
+            let next = chars.get(n + 1).map(|&(_, c)| c);
+
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && matches!(next, Some(next) if next.is_lowercase())));
+
+            if boundary {
+                words.push(&part[start..i]);
+                start = i;
+            }
+        }
+
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_camel_case, to_pascal_case, to_screaming_snake_case, to_snake_case};
+    use Cons;
+
+    #[test]
+    fn test_conversions() {
+        let cases = [
+            (
+                "HTTPServer",
+                "httpServer",
+                "HttpServer",
+                "http_server",
+                "HTTP_SERVER",
+            ),
+            (
+                "http_server",
+                "httpServer",
+                "HttpServer",
+                "http_server",
+                "HTTP_SERVER",
+            ),
+            (
+                "getHTTPResponseCode",
+                "getHttpResponseCode",
+                "GetHttpResponseCode",
+                "get_http_response_code",
+                "GET_HTTP_RESPONSE_CODE",
+            ),
+            (
+                "base64Encode",
+                "base64Encode",
+                "Base64Encode",
+                "base64_encode",
+                "BASE64_ENCODE",
+            ),
+            (
+                "user-id v2",
+                "userIdV2",
+                "UserIdV2",
+                "user_id_v2",
+                "USER_ID_V2",
+            ),
+            (
+                "MAX_VALUE",
+                "maxValue",
+                "MaxValue",
+                "max_value",
+                "MAX_VALUE",
+            ),
+            ("", "", "", "", ""),
+        ];
+
+        for &(input, camel, pascal, snake, screaming) in cases.iter() {
+            assert_eq!(camel, to_camel_case(input), "camel case of {:?}", input);
+            assert_eq!(pascal, to_pascal_case(input), "pascal case of {:?}", input);
+            assert_eq!(snake, to_snake_case(input), "snake case of {:?}", input);
+            assert_eq!(
+                screaming,
+                to_screaming_snake_case(input),
+                "screaming of {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_cons() {
+        let name = Cons::from(String::from("XMLHttpRequest"));
+        assert_eq!("xml_http_request", to_snake_case(&name));
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::collections::BTreeSet;
 use std::fmt::{self, Write};
-use {Cons, Custom, Formatter, IntoTokens, Tokens};
+use {Cons, Custom, Formatter, IntoTokens, QuoteStyle, Tokens};
 
 static SEP: &'static str = ".";
 
@@ -110,33 +110,54 @@ impl<'el> Python<'el> {
     }
 }
 
+/// Extra data for formatting.
+#[derive(Debug, Default)]
+pub struct Extra {
+    /// Delimiter of quoted strings.
+    quote_style: QuoteStyle,
+}
+
+impl Extra {
+    /// Set the delimiter of quoted strings.
+    ///
+    /// Only the chosen delimiter is escaped inside of the string.
+    pub fn quote_style(&mut self, quote_style: QuoteStyle) {
+        self.quote_style = quote_style;
+    }
+}
+
 impl<'el> Custom for Python<'el> {
-    type Extra = ();
+    type Extra = Extra;
+
+    fn line_comment_prefix() -> &'static str {
+        "# "
+    }
+
+    fn requires_trailing_semicolon() -> bool {
+        false
+    }
 
     fn format(&self, out: &mut Formatter, _extra: &mut Self::Extra, _level: usize) -> fmt::Result {
         write!(out, "{}", self)
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
-
-        for c in input.chars() {
-            match c {
-                '\t' => out.write_str("\\t")?,
-                '\u{0007}' => out.write_str("\\b")?,
-                '\n' => out.write_str("\\n")?,
-                '\r' => out.write_str("\\r")?,
-                '\u{0014}' => out.write_str("\\f")?,
-                '\'' => out.write_str("\\'")?,
-                '"' => out.write_str("\\\"")?,
-                '\\' => out.write_str("\\\\")?,
-                c => out.write_char(c)?,
-            };
-        }
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
-        out.write_char('"')?;
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
+        QuoteStyle::Double.escape(out, input)
+    }
 
-        Ok(())
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
+    fn quote_string_with(out: &mut Formatter, extra: &mut Self::Extra, input: &str) -> fmt::Result {
+        let delimiter = extra.quote_style.delimiter();
+        out.write_char(delimiter)?;
+        extra.quote_style.escape(out, input)?;
+        out.write_char(delimiter)
     }
 
     fn write_file<'a>(
@@ -182,9 +203,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{imported, local, Python};
+    use super::{imported, local, Extra, Python};
     use quoted::Quoted;
     use tokens::Tokens;
+    use QuoteStyle;
 
     #[test]
     fn test_string() {
@@ -193,6 +215,29 @@ mod tests {
         assert_eq!("\"hello \\n world\"", toks.to_string().unwrap().as_str());
     }
 
+    #[test]
+    fn test_control_escapes() {
+        let toks: Tokens<Python> = toks!["\u{0007}\u{0008}\u{000C}\u{0014}".quoted()];
+        assert_eq!(
+            "\"\u{0007}\\b\\f\u{0014}\"",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_comment() {
+        use element::Element;
+
+        let mut toks: Tokens<Python> = Tokens::new();
+        toks.push("def foo():");
+        toks.nested(toks![Element::Comment("hello".into()), "pass"]);
+
+        assert_eq!(
+            "def foo():\n  # hello\n  pass\n",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
     #[test]
     fn test_imported() {
         let mut toks: Tokens<Python> = Tokens::new();
@@ -216,4 +261,20 @@ mod tests {
 
         assert_eq!(Ok("dict\n"), toks.to_file().as_ref().map(|s| s.as_str()));
     }
+
+    #[test]
+    fn test_quote_style() {
+        let toks: Tokens<Python> = toks!["it's \"quoted\"".quoted()];
+        assert_eq!(
+            "\"it's \\\"quoted\\\"\"",
+            toks.clone().to_string().unwrap().as_str()
+        );
+
+        let mut extra = Extra::default();
+        extra.quote_style(QuoteStyle::Single);
+        assert_eq!(
+            "'it\\'s \"quoted\"'",
+            toks.to_string_with(extra).unwrap().as_str()
+        );
+    }
 }
@@ -2,8 +2,55 @@
 
 use super::cons::Cons;
 use super::element::Element;
+use super::formatter::Formatter;
+use std::fmt::{self, Write};
 use std::rc::Rc;
 
+/// Delimiter of quoted strings, for languages which accept more than one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// Double quotes, `"hello"`.
+    #[default]
+    Double,
+    /// Single quotes, `'hello'`.
+    Single,
+}
+
+impl QuoteStyle {
+    /// The delimiter character of the style.
+    pub fn delimiter(self) -> char {
+        match self {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Single => '\'',
+        }
+    }
+
+    /// Escape the content of a string quoted with this style, using C-style escapes.
+    ///
+    /// Only the delimiter of the style is escaped, the other quote is written as-is.
+    pub fn escape(self, out: &mut Formatter, input: &str) -> fmt::Result {
+        let delimiter = self.delimiter();
+
+        for c in input.chars() {
+            match c {
+                '\t' => out.write_str("\\t")?,
+                '\u{0008}' => out.write_str("\\b")?,
+                '\n' => out.write_str("\\n")?,
+                '\r' => out.write_str("\\r")?,
+                '\u{000C}' => out.write_str("\\f")?,
+                '\\' => out.write_str("\\\\")?,
+                c if c == delimiter => {
+                    out.write_char('\\')?;
+                    out.write_char(c)?;
+                }
+                c => out.write_char(c)?,
+            };
+        }
+
+        Ok(())
+    }
+}
+
 /// Trait to convert types to quoted elements.
 pub trait Quoted<'el> {
     /// Convert type to quoted element.
@@ -28,6 +75,12 @@ impl<'el> Quoted<'el> for Rc<String> {
     }
 }
 
+impl<'el> Quoted<'el> for char {
+    fn quoted<C>(self) -> Element<'el, C> {
+        Element::QuotedChar(self)
+    }
+}
+
 impl<'el> Quoted<'el> for Cons<'el> {
     fn quoted<C>(self) -> Element<'el, C> {
         Element::Quoted(self)
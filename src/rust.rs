@@ -244,9 +244,17 @@ impl<'el> Custom for Rust<'el> {
         self.name.format(out, extra, level)
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
+    fn quote_char(out: &mut Formatter, _extra: &mut Self::Extra, c: char) -> fmt::Result {
+        out.write_char('\'')?;
+        escape_char(out, c)?;
+        out.write_char('\'')
+    }
+
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
         for c in input.chars() {
             match c {
                 '\t' => out.write_str("\\t")?,
@@ -259,10 +267,13 @@ impl<'el> Custom for Rust<'el> {
             };
         }
 
-        out.write_char('"')?;
         Ok(())
     }
 
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
     fn write_file<'a>(
         tokens: Tokens<'a, Self>,
         out: &mut Formatter,
@@ -307,6 +318,20 @@ where
     }
 }
 
+/// Escape a character for use in a character literal.
+fn escape_char(out: &mut Formatter, c: char) -> fmt::Result {
+    match c {
+        '\n' => out.write_str("\\n"),
+        '\r' => out.write_str("\\r"),
+        '\t' => out.write_str("\\t"),
+        '\0' => out.write_str("\\0"),
+        '\\' => out.write_str("\\\\"),
+        '\'' => out.write_str("\\'"),
+        c if c.is_control() => write!(out, "\\u{{{:x}}}", c as u32),
+        c => out.write_char(c),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{imported, local};
@@ -323,6 +348,23 @@ mod tests {
         assert_eq!(Ok("\"hello \\n world\""), res.as_ref().map(|s| s.as_str()));
     }
 
+    #[test]
+    fn test_char() {
+        let toks: Tokens<Rust> = toks![
+            'a'.quoted(),
+            '"'.quoted(),
+            '\''.quoted(),
+            '\0'.quoted(),
+            '\u{001B}'.quoted()
+        ]
+        .join_spacing();
+
+        assert_eq!(
+            Ok("'a' '\"' '\\'' '\\0' '\\u{1b}'"),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
     #[test]
     fn test_imported() {
         let dbg = imported("std::fmt", "Debug");
@@ -14,6 +14,8 @@ pub struct Argument<'el> {
     name: Cons<'el>,
 
     initializer: Tokens<'el, Swift<'el>>,
+    /// If the argument is `@autoclosure`.
+    autoclosure: bool,
 }
 
 impl<'el> Argument<'el> {
@@ -27,6 +29,7 @@ impl<'el> Argument<'el> {
             ty: ty.into(),
             name: name.into(),
             initializer: Tokens::new(),
+            autoclosure: false,
         }
     }
 
@@ -38,6 +41,12 @@ impl<'el> Argument<'el> {
         self.initializer.append(initializer.into_tokens())
     }
 
+    /// Mark the argument as `@autoclosure`, which is only valid for function types without
+    /// arguments.
+    pub fn autoclosure(&mut self, autoclosure: bool) {
+        self.autoclosure = autoclosure;
+    }
+
     /// Get the variable of the argument.
     pub fn var(&self) -> Cons<'el> {
         self.name.clone()
@@ -53,9 +62,25 @@ into_tokens_impl_from!(Argument<'el>, Swift<'el>);
 
 impl<'el> IntoTokens<'el, Swift<'el>> for Argument<'el> {
     fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        if self.autoclosure {
+            debug_assert!(
+                match self.ty {
+                    Swift::Function { ref arguments, .. } => arguments.is_empty(),
+                    _ => false,
+                },
+                "`@autoclosure` is only allowed on function types without arguments: {}",
+                self.name.as_str()
+            );
+        }
+
         let mut s = Tokens::new();
         s.append(self.name);
         s.append(":");
+
+        if self.autoclosure {
+            s.append("@autoclosure");
+        }
+
         s.append(self.ty);
         if !self.initializer.is_empty() {
             s.append("=");
@@ -71,7 +96,7 @@ mod tests {
     use cons::Cons;
     use swift::argument::Argument;
     use swift::Swift::Type;
-    use swift::{local, Name, Swift};
+    use swift::{function, local, Name, Swift};
     use tokens::Tokens;
 
     #[test]
@@ -87,4 +112,36 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("arg : Int = 100"), out);
     }
+
+    #[test]
+    fn test_autoclosure() {
+        let mut condition =
+            Argument::new(function(Vec::<Swift>::new(), local("Bool")), "condition");
+        condition.autoclosure(true);
+
+        let mut message = Argument::new(
+            function(Vec::<Swift>::new(), local("String")).escaping(),
+            "message",
+        );
+        message.autoclosure(true);
+
+        let t: Tokens<Swift> = toks![Tokens::from(condition), ", ", Tokens::from(message)];
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok("condition : @autoclosure () -> Bool, message : @autoclosure @escaping () -> String"),
+            out
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`@autoclosure` is only allowed")]
+    #[cfg(debug_assertions)]
+    fn test_autoclosure_not_function() {
+        let mut c = Argument::new(local("Bool"), "condition");
+        c.autoclosure(true);
+
+        let _: Tokens<Swift> = c.into();
+    }
 }
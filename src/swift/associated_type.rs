@@ -0,0 +1,112 @@
+//! Data structure for associated types.
+
+use cons::Cons;
+use into_tokens::IntoTokens;
+use swift::Swift;
+use tokens::Tokens;
+use Element;
+
+/// Model for an associated type of a protocol, like `associatedtype Element : Hashable`.
+#[derive(Debug, Clone)]
+pub struct AssociatedType<'el> {
+    /// What the associated type must conform to.
+    pub inherits: Vec<Swift<'el>>,
+    /// Default type.
+    pub default: Option<Swift<'el>>,
+    /// Constraints of the `where` clause, like `Element.Key == String`.
+    pub constraints: Tokens<'el, Swift<'el>>,
+    /// Name of the associated type.
+    name: Cons<'el>,
+}
+
+impl<'el> AssociatedType<'el> {
+    /// Build a new unconstrained associated type.
+    pub fn new<N>(name: N) -> AssociatedType<'el>
+    where
+        N: Into<Cons<'el>>,
+    {
+        AssociatedType {
+            inherits: vec![],
+            default: None,
+            constraints: Tokens::new(),
+            name: name.into(),
+        }
+    }
+
+    /// Name of the associated type.
+    pub fn name(&self) -> Cons<'el> {
+        self.name.clone()
+    }
+}
+
+into_tokens_impl_from!(AssociatedType<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for AssociatedType<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        let mut sig = Tokens::new();
+
+        sig.append("associatedtype");
+        sig.append(self.name);
+
+        if !self.inherits.is_empty() {
+            let inherits: Tokens<_> = self.inherits.into_iter().map(Element::from).collect();
+
+            sig.append(":");
+            sig.append(inherits.join(", "));
+        }
+
+        if let Some(default) = self.default {
+            sig.append("=");
+            sig.append(default);
+        }
+
+        if !self.constraints.is_empty() {
+            sig.append("where");
+            sig.append(self.constraints.join(", "));
+        }
+
+        sig.join_spacing()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swift::associated_type::AssociatedType;
+    use swift::protocol::Protocol;
+    use swift::{local, Method, Swift};
+    use Tokens;
+
+    #[test]
+    fn test_where() {
+        let mut element = AssociatedType::new("Element");
+        element
+            .constraints
+            .append(toks!["Element : ", local("Equatable")]);
+
+        let mut index = AssociatedType::new("Index");
+        index.inherits = vec![local("Comparable")];
+        index.default = Some(local("Int"));
+        index.constraints.append("Index.Stride == Int");
+        index.constraints.append("Index : Hashable");
+
+        let mut p = Protocol::new("Container");
+        p.associated_types = vec![element, index];
+        p.methods.push(Method::new("clear"));
+
+        let t: Tokens<Swift> = p.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "public protocol Container {\n",
+                "  associatedtype Element where Element : Equatable\n",
+                "  associatedtype Index : Comparable = Int where Index.Stride == Int, Index : Hashable\n",
+                "\n",
+                "  public func clear();\n",
+                "}",
+            )),
+            out
+        );
+    }
+}
@@ -4,6 +4,7 @@ use swift::constructor::Constructor;
 use swift::field::Field;
 use swift::method::Method;
 use swift::modifier::Modifier;
+use swift::subscript::Subscript;
 use swift::Swift;
 use {Cons, IntoTokens};
 use {Element, Tokens};
@@ -17,6 +18,8 @@ pub struct Class<'el> {
     pub fields: Vec<Field<'el>>,
     /// Declared methods.
     pub constructors: Vec<Constructor<'el>>,
+    /// Declared subscripts.
+    pub subscripts: Vec<Subscript<'el>>,
     /// Declared methods.
     pub methods: Vec<Method<'el>>,
     /// What this class implements.
@@ -40,6 +43,7 @@ impl<'el> Class<'el> {
         Class {
             modifiers: vec![Modifier::Public],
             fields: vec![],
+            subscripts: vec![],
             methods: vec![],
             constructors: vec![],
             implements: vec![],
@@ -62,12 +66,38 @@ impl<'el> Class<'el> {
     pub fn name(&self) -> Cons<'el> {
         self.name.clone()
     }
+
+    /// Assert that the class is immutable, panicking if any stored field is declared with `var`.
+    ///
+    /// Computed properties are allowed.
+    pub fn assert_immutable(&self) {
+        for field in &self.fields {
+            assert!(
+                field.is_computed() || !field.is_mutable(),
+                "stored field `{}` of immutable class {} must not be a `var`",
+                field.var().as_str(),
+                self.name.as_str()
+            );
+        }
+    }
 }
 
 into_tokens_impl_from!(Class<'el>, Swift<'el>);
 
 impl<'el> IntoTokens<'el, Swift<'el>> for Class<'el> {
     fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        debug_assert!(
+            self.methods
+                .iter()
+                .all(|m| !m.modifiers.contains(&Modifier::Mutating))
+                && self
+                    .subscripts
+                    .iter()
+                    .all(|s| !s.modifiers.contains(&Modifier::Mutating)),
+            "`mutating` is only allowed on struct, enum and protocol methods, not in class {}",
+            self.name.as_str()
+        );
+
         let mut sig = Tokens::new();
 
         sig.extend(self.modifiers.into_tokens());
@@ -121,6 +151,10 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Class<'el> {
                 }
             }
 
+            for subscript in self.subscripts {
+                body.push(subscript);
+            }
+
             if !self.methods.is_empty() {
                 for method in self.methods {
                     body.push(method);
@@ -143,7 +177,7 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Class<'el> {
 #[cfg(test)]
 mod tests {
     use swift::class::Class;
-    use swift::{local, Swift};
+    use swift::{local, Field, Method, Modifier, Swift};
     use Tokens;
 
     #[test]
@@ -158,4 +192,35 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public class Foo<T> : Super {\n}"), out);
     }
+
+    #[test]
+    #[should_panic(expected = "`mutating` is only allowed")]
+    #[cfg(debug_assertions)]
+    fn test_mutating_method() {
+        let mut method = Method::new("reset");
+        method.modifiers.push(Modifier::Mutating);
+
+        let mut c = Class::new("Foo");
+        c.methods.push(method);
+
+        let _: Tokens<Swift> = c.into();
+    }
+
+    #[test]
+    #[should_panic(expected = "stored field `count` of immutable class Foo")]
+    fn test_assert_immutable() {
+        let mut computed = Field::new(local("Int"), "double");
+        computed.mutable(true);
+        computed.getter(toks!["count * 2"]);
+
+        let mut c = Class::new("Foo");
+        c.fields.push(Field::new(local("String"), "name"));
+        c.fields.push(computed);
+        c.assert_immutable();
+
+        let mut count = Field::new(local("Int"), "count");
+        count.mutable(true);
+        c.fields.push(count);
+        c.assert_immutable();
+    }
 }
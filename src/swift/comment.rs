@@ -1,9 +1,17 @@
 use swift::Swift;
+use wrap::wrap_lines;
 use {Cons, Element, IntoTokens, Tokens};
 
 /// Format a block comment, starting with `/**`, and ending in `*/`.
 pub struct BlockComment<'el>(pub Vec<Cons<'el>>);
 
+impl<'el> BlockComment<'el> {
+    /// Word-wrap the lines of the comment at the given width, not counting the ` * ` prefix.
+    pub fn wrap(self, width: usize) -> BlockComment<'el> {
+        BlockComment(wrap_lines(self.0, width))
+    }
+}
+
 impl<'el> IntoTokens<'el, Swift<'el>> for BlockComment<'el> {
     fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
         let mut t = Tokens::new();
@@ -25,3 +33,27 @@ impl<'el> IntoTokens<'el, Swift<'el>> for BlockComment<'el> {
         t
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BlockComment;
+    use tokens::Tokens;
+    use IntoTokens;
+
+    #[test]
+    fn test_wrap() {
+        let comment = BlockComment(vec![
+            "Hello there big world".into(),
+            "".into(),
+            "Bye".into(),
+        ]);
+        let t: Tokens<_> = comment.wrap(11).into_tokens();
+
+        assert_eq!(
+            Ok(String::from(
+                "/**\n * Hello there\n * big world\n *\n * Bye\n */\n"
+            )),
+            t.to_string()
+        );
+    }
+}
@@ -9,6 +9,15 @@ use into_tokens::IntoTokens;
 use swift::Swift;
 use tokens::Tokens;
 
+/// Failability of an initializer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failability {
+    /// A failable initializer, `init?`.
+    Optional,
+    /// An implicitly unwrapped failable initializer, `init!`.
+    ImplicitlyUnwrapped,
+}
+
 /// Model for Java Constructors.
 #[derive(Debug, Clone)]
 pub struct Constructor<'el> {
@@ -20,6 +29,8 @@ pub struct Constructor<'el> {
     pub body: Tokens<'el, Swift<'el>>,
     /// Exception thrown by the constructor.
     pub throws: bool,
+    /// If the initializer is failable.
+    pub failable: Option<Failability>,
 }
 
 impl<'el> Constructor<'el> {
@@ -29,6 +40,7 @@ impl<'el> Constructor<'el> {
             modifiers: vec![Modifier::Public],
             arguments: Vec::new(),
             throws: false,
+            failable: None,
             body: Tokens::new(),
         }
     }
@@ -40,23 +52,28 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Constructor<'el> {
     fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
         use self::Element::*;
 
-        let mut c = self;
+        let c = self;
 
         let args: Vec<Tokens<Swift>> = c.arguments.into_iter().map(|a| a.into_tokens()).collect();
         let args: Tokens<Swift> = args.into_tokens();
 
         let mut sig: Tokens<Swift> = Tokens::new();
 
-        c.modifiers.sort();
-        sig.extend(c.modifiers.into_iter().map(Into::into));
+        sig.extend(c.modifiers.into_tokens());
+
+        let init = match c.failable {
+            Some(Failability::Optional) => "init?",
+            Some(Failability::ImplicitlyUnwrapped) => "init!",
+            Option::None => "init",
+        };
 
         if !args.is_empty() {
             let sep = toks![",", PushSpacing];
             let args = args.join(sep);
 
-            sig.append(toks!["init", "(", Nested(Owned(args)), ")",]);
+            sig.append(toks![init, "(", Nested(Owned(args)), ")",]);
         } else {
-            sig.append(toks!["init", "()"]);
+            sig.append(toks![init, "()"]);
         }
 
         if c.throws {
@@ -75,8 +92,9 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Constructor<'el> {
 
 #[cfg(test)]
 mod tests {
-    use super::Constructor;
+    use super::{Constructor, Failability};
     use cons::Cons;
+    use swift::Modifier;
     use swift::Swift;
     use tokens::Tokens;
 
@@ -100,4 +118,26 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public init() throws {\n}"), out);
     }
+
+    #[test]
+    fn test_failable() {
+        let mut c = Constructor::new();
+        c.modifiers.push(Modifier::Convenience);
+        c.failable = Some(Failability::Optional);
+        c.throws = true;
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("public convenience init?() throws {\n}"), out);
+
+        let mut c = Constructor::new();
+        c.modifiers = vec![Modifier::Convenience];
+        c.failable = Some(Failability::ImplicitlyUnwrapped);
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("convenience init!() {\n}"), out);
+    }
 }
@@ -0,0 +1,122 @@
+//! Data structure for do/catch statements.
+
+use swift::Swift;
+use {Cons, IntoTokens, Tokens};
+
+/// A catch clause of a do statement.
+#[derive(Debug, Clone)]
+pub struct Catch<'el> {
+    /// Pattern matched by the clause, catching every error if absent.
+    pub pattern: Option<Tokens<'el, Swift<'el>>>,
+    /// Body of the clause.
+    pub body: Tokens<'el, Swift<'el>>,
+}
+
+impl<'el> Catch<'el> {
+    /// Build a clause catching every error, like `catch { ... }`.
+    pub fn new<B>(body: B) -> Catch<'el>
+    where
+        B: IntoTokens<'el, Swift<'el>>,
+    {
+        Catch {
+            pattern: None,
+            body: body.into_tokens(),
+        }
+    }
+
+    /// Build a clause catching errors of the given type, like `catch let e as Foo { ... }`.
+    pub fn typed<N, T, B>(name: N, ty: T, body: B) -> Catch<'el>
+    where
+        N: Into<Cons<'el>>,
+        T: Into<Swift<'el>>,
+        B: IntoTokens<'el, Swift<'el>>,
+    {
+        Catch {
+            pattern: Some(toks!["let ", name.into(), " as ", ty.into()]),
+            body: body.into_tokens(),
+        }
+    }
+}
+
+/// Setup a `do { ... } catch { ... }` statement.
+///
+/// A clause catching every error must be the last one.
+pub fn do_catch<'el, B, I>(body: B, catches: I) -> Tokens<'el, Swift<'el>>
+where
+    B: IntoTokens<'el, Swift<'el>>,
+    I: IntoIterator<Item = Catch<'el>>,
+{
+    let mut t = Tokens::new();
+    t.push("do {");
+    t.nested(body.into_tokens());
+
+    let mut exhaustive = false;
+
+    for catch in catches {
+        debug_assert!(!exhaustive, "a catch clause without a pattern must be last");
+
+        match catch.pattern {
+            Some(pattern) => t.push(toks!["} catch ", pattern, " {"]),
+            None => {
+                exhaustive = true;
+                t.push("} catch {");
+            }
+        }
+
+        t.nested(catch.body);
+    }
+
+    t.push("}");
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{do_catch, Catch};
+    use swift::{imported, Swift};
+    use Tokens;
+
+    #[test]
+    fn test_do_catch() {
+        let toks: Tokens<Swift> = do_catch(
+            "try load()",
+            vec![
+                Catch::typed("e", imported("Network", "NetworkError"), "retry(after: e)"),
+                Catch {
+                    pattern: Some(toks!["is CancellationError"]),
+                    body: Tokens::new(),
+                },
+                Catch::new("print(error)"),
+            ],
+        );
+
+        assert_eq!(
+            Ok(concat!(
+                "import Network\n",
+                "\n",
+                "do {\n",
+                "  try load()\n",
+                "} catch let e as NetworkError {\n",
+                "  retry(after: e)\n",
+                "} catch is CancellationError {\n",
+                "} catch {\n",
+                "  print(error)\n",
+                "}\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_bare_catch_must_be_last() {
+        let _: Tokens<Swift> = do_catch(
+            "try load()",
+            vec![
+                Catch::new("print(error)"),
+                Catch::typed("e", imported("Network", "NetworkError"), "retry()"),
+            ],
+        );
+    }
+}
@@ -1,10 +1,12 @@
 //! Data structure for enums.
 
-use swift::constructor::Constructor;
+use swift::argument::Argument;
+use swift::constructor::{Constructor, Failability};
 use swift::field::Field;
 use swift::method::Method;
 use swift::modifier::Modifier;
-use swift::Swift;
+use swift::switch::{switch, Case};
+use swift::{array, local, main_attribute, Swift};
 use {Cons, IntoTokens};
 use {Element, Tokens};
 
@@ -13,8 +15,12 @@ use {Element, Tokens};
 pub struct Enum<'el> {
     /// Variants of the enum.
     pub variants: Tokens<'el, Swift<'el>>,
+    /// Nested type declarations, like a `Struct`, `Class` or `Enum`, rendered after the variants.
+    pub nested: Vec<Tokens<'el, Swift<'el>>>,
     /// Enum modifiers.
     pub modifiers: Vec<Modifier>,
+    /// If the whole enum is `indirect`.
+    pub indirect: bool,
     /// Declared methods.
     pub fields: Vec<Field<'el>>,
     /// Declared methods.
@@ -23,6 +29,8 @@ pub struct Enum<'el> {
     pub methods: Vec<Method<'el>>,
     /// Generic parameters.
     pub parameters: Tokens<'el, Swift<'el>>,
+    /// What this enum implements.
+    pub implements: Vec<Swift<'el>>,
     /// Annotations for the constructor.
     attributes: Tokens<'el, Swift<'el>>,
     /// Name of enum.
@@ -37,13 +45,16 @@ impl<'el> Enum<'el> {
     {
         Enum {
             variants: Tokens::new(),
+            nested: vec![],
             modifiers: vec![Modifier::Public],
+            indirect: false,
             fields: vec![],
             methods: vec![],
             constructors: vec![],
             attributes: Tokens::new(),
             name: name.into(),
             parameters: Tokens::new(),
+            implements: vec![],
         }
     }
 
@@ -59,6 +70,19 @@ impl<'el> Enum<'el> {
     pub fn name(&self) -> Cons<'el> {
         self.name.clone()
     }
+
+    /// Make this the entry point of the program, with `@main` and a `static func main()`.
+    pub fn entry_point<B>(&mut self, body: B)
+    where
+        B: IntoTokens<'el, Swift<'el>>,
+    {
+        let mut main = Method::new("main");
+        main.modifiers.push(Modifier::Static);
+        main.body = body.into_tokens();
+
+        self.attributes.push(main_attribute());
+        self.methods.push(main);
+    }
 }
 
 into_tokens_impl_from!(Enum<'el>, Swift<'el>);
@@ -70,6 +94,11 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Enum<'el> {
         let mut sig = Tokens::new();
 
         sig.extend(self.modifiers.into_tokens());
+
+        if self.indirect {
+            sig.append("indirect");
+        }
+
         sig.append("enum");
 
         sig.append({
@@ -86,6 +115,17 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Enum<'el> {
             t
         });
 
+        if !self.implements.is_empty() {
+            let implements: Tokens<_> = self
+                .implements
+                .into_iter()
+                .map::<Element<_>, _>(Into::into)
+                .collect();
+
+            sig.append(":");
+            sig.append(implements.join(", "));
+        }
+
         let mut s = Tokens::new();
 
         if !self.attributes.is_empty() {
@@ -105,6 +145,10 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Enum<'el> {
             }
             // different from class end
 
+            for nested in self.nested {
+                body.push(nested);
+            }
+
             if !self.fields.is_empty() {
                 for field in self.fields {
                     body.push(field);
@@ -132,10 +176,58 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Enum<'el> {
     }
 }
 
+/// Build an explicit `static var allCases : [Self]`, listing the given cases in order.
+///
+/// Useful for enums with associated values, where `CaseIterable` can't be synthesized.
+pub fn all_cases<'el, I>(cases: I) -> Field<'el>
+where
+    I: IntoIterator,
+    I::Item: Into<Cons<'el>>,
+{
+    let cases: Tokens<'el, Swift<'el>> = cases
+        .into_iter()
+        .map(|case| toks![".", case.into()])
+        .collect::<Vec<_>>()
+        .into_tokens();
+
+    let mut field = Field::result_builder(
+        array(local("Self")),
+        "allCases",
+        vec![toks!["[", cases.join(", "), "]"]],
+    );
+    field.modifiers = vec![Modifier::Public, Modifier::Static];
+    field
+}
+
+/// Build an explicit failable `init?(rawValue:)`, mapping each raw value to its case.
+pub fn raw_value_init<'el, T, I, N, V>(raw_type: T, cases: I) -> Constructor<'el>
+where
+    T: Into<Swift<'el>>,
+    I: IntoIterator<Item = (N, V)>,
+    N: Into<Cons<'el>>,
+    V: IntoTokens<'el, Swift<'el>>,
+{
+    let cases: Vec<Case<'el>> = cases
+        .into_iter()
+        .map(|(name, value)| {
+            let mut case = Case::new(value);
+            case.body.push(toks!["self = .", name.into()]);
+            case
+        })
+        .collect();
+
+    let mut c = Constructor::new();
+    c.failable = Some(Failability::Optional);
+    c.arguments.push(Argument::new(raw_type, "rawValue"));
+    c.body = switch("rawValue", cases, Some(toks!["return nil"]));
+    c
+}
+
 #[cfg(test)]
 mod tests {
-    use swift::enum_::Enum;
-    use swift::Swift;
+    use swift::enum_::{all_cases, raw_value_init, Enum};
+    use swift::{imported, local, Field, Struct, Swift};
+    use Quoted;
     use Tokens;
 
     #[test]
@@ -153,4 +245,129 @@ mod tests {
             out
         );
     }
+
+    #[test]
+    fn test_implements() {
+        let mut c = Enum::new("Foo");
+        c.variants.append("case BAR");
+        c.implements = vec![imported("Foundation", "Codable"), local("Equatable")];
+
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_file();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok("import Foundation\n\npublic enum Foo : Codable, Equatable {\n  case BAR\n}\n",),
+            out
+        );
+    }
+
+    #[test]
+    fn test_entry_point() {
+        let mut c = Enum::new("App");
+        c.entry_point(toks!["print(\"Hello\")"]);
+
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "@main\n",
+                "public enum App {\n",
+                "  public static func main() {\n",
+                "    print(\"Hello\")\n",
+                "  }\n",
+                "}",
+            )),
+            out
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut inner = Struct::new("Inner");
+        inner
+            .fields
+            .push(Field::new(imported("Foundation", "Date"), "date"));
+
+        let mut c = Enum::new("Namespace");
+        c.variants.append("case a");
+        c.nested.push(inner.into());
+        c.nested.push(Enum::new("Other").into());
+
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_file();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "import Foundation\n",
+                "\n",
+                "public enum Namespace {\n",
+                "  case a\n",
+                "\n",
+                "  public struct Inner {\n",
+                "    private let date : Date\n",
+                "  }\n",
+                "\n",
+                "  public enum Other {\n",
+                "  }\n",
+                "}\n",
+            )),
+            out
+        );
+    }
+
+    #[test]
+    fn test_synthesized_conformances() {
+        let mut c = Enum::new("Shape");
+        c.variants.append("case circle(Double)");
+        c.variants.append("case square(Double)");
+        c.fields.push(all_cases(vec!["circle(1)", "square(1)"]));
+
+        let mut d = Enum::new("Kind");
+        d.variants.append("case a");
+        d.variants.append("case b");
+        d.constructors.push(raw_value_init(
+            local("String"),
+            vec![("a", "A".quoted()), ("b", "B".quoted())],
+        ));
+
+        let t: Tokens<Swift> = toks![Tokens::from(c), Tokens::from(d)].join_line_spacing();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "public enum Shape {\n",
+                "  case circle(Double)\n",
+                "  case square(Double)\n",
+                "\n",
+                "  public static var allCases : [Self] {\n",
+                "    [.circle(1), .square(1)]\n",
+                "  }\n",
+                "}\n",
+                "\n",
+                "public enum Kind {\n",
+                "  case a\n",
+                "  case b\n",
+                "\n",
+                "  public init?(\n",
+                "    rawValue : String\n",
+                "  ) {\n",
+                "    switch rawValue {\n",
+                "    case \"A\":\n",
+                "      self = .a\n",
+                "    case \"B\":\n",
+                "      self = .b\n",
+                "    default:\n",
+                "      return nil\n",
+                "    }\n",
+                "  }\n",
+                "}",
+            )),
+            out
+        );
+    }
 }
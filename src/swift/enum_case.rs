@@ -0,0 +1,104 @@
+//! Data structure for enum cases.
+
+use cons::Cons;
+use into_tokens::IntoTokens;
+use swift::Swift;
+use tokens::Tokens;
+use Element;
+
+/// Model for a single Swift enum case, like `indirect case node(Tree, Tree)`.
+#[derive(Debug, Clone)]
+pub struct EnumCase<'el> {
+    /// Types of the associated values.
+    pub values: Vec<Swift<'el>>,
+    /// If the case is `indirect`.
+    pub indirect: bool,
+    /// Name of the case.
+    name: Cons<'el>,
+}
+
+impl<'el> EnumCase<'el> {
+    /// Build a new case without associated values.
+    pub fn new<N>(name: N) -> EnumCase<'el>
+    where
+        N: Into<Cons<'el>>,
+    {
+        EnumCase {
+            values: vec![],
+            indirect: false,
+            name: name.into(),
+        }
+    }
+
+    /// Name of the case.
+    pub fn name(&self) -> Cons<'el> {
+        self.name.clone()
+    }
+}
+
+into_tokens_impl_from!(EnumCase<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for EnumCase<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        let mut t = Tokens::new();
+
+        if self.indirect {
+            t.append("indirect ");
+        }
+
+        t.append("case ");
+        t.append(self.name);
+
+        if !self.values.is_empty() {
+            let values: Tokens<_> = self.values.into_iter().map(Element::from).collect();
+
+            t.append("(");
+            t.append(values.join(", "));
+            t.append(")");
+        }
+
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swift::enum_::Enum;
+    use swift::enum_case::EnumCase;
+    use swift::{local, Swift};
+    use tokens::Tokens;
+    use IntoTokens;
+
+    #[test]
+    fn test_indirect() {
+        let mut node = EnumCase::new("node");
+        node.values = vec![local("Tree"), local("Tree")];
+        node.indirect = true;
+
+        let mut tree = Enum::new("Tree");
+        tree.variants.append(EnumCase::new("leaf").into_tokens());
+        tree.variants.append(node.into_tokens());
+
+        let mut list = Enum::new("List");
+        list.indirect = true;
+        list.variants.append(EnumCase::new("empty").into_tokens());
+
+        let t: Tokens<Swift> = toks![Tokens::from(tree), Tokens::from(list)].join_line_spacing();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "public enum Tree {\n",
+                "  case leaf\n",
+                "  indirect case node(Tree, Tree)\n",
+                "}\n",
+                "\n",
+                "public indirect enum List {\n",
+                "  case empty\n",
+                "}",
+            )),
+            out
+        );
+    }
+}
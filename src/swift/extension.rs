@@ -4,6 +4,7 @@ use swift::constructor::Constructor;
 use swift::field::Field;
 use swift::method::Method;
 use swift::modifier::Modifier;
+use swift::subscript::Subscript;
 use swift::{local, Name, Swift};
 use {Cons, IntoTokens};
 use {Element, Tokens};
@@ -17,10 +18,14 @@ pub struct Extension<'el> {
     pub fields: Vec<Field<'el>>,
     /// Declared methods.
     pub constructors: Vec<Constructor<'el>>,
+    /// Declared subscripts.
+    pub subscripts: Vec<Subscript<'el>>,
     /// Declared methods.
     pub methods: Vec<Method<'el>>,
     /// What this class implements.
     pub implements: Vec<Swift<'el>>,
+    /// Generic constraints in the `where` clause, like `Element : Equatable`.
+    pub where_clauses: Vec<Tokens<'el, Swift<'el>>>,
     /// Generic parameters.
     pub parameters: Tokens<'el, Swift<'el>>,
     /// Annotations for the constructor.
@@ -38,9 +43,11 @@ impl<'el> Extension<'el> {
         Extension {
             modifiers: vec![Modifier::Public],
             fields: vec![],
+            subscripts: vec![],
             methods: vec![],
             constructors: vec![],
             implements: vec![],
+            where_clauses: vec![],
             parameters: Tokens::new(),
             attributes: Tokens::new(),
             ty: ty.into(),
@@ -95,6 +102,13 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Extension<'el> {
             sig.append(implements.join(", "));
         }
 
+        if !self.where_clauses.is_empty() {
+            let where_clauses: Tokens<_> = self.where_clauses.into_tokens();
+
+            sig.append("where");
+            sig.append(where_clauses.join(", "));
+        }
+
         let mut s = Tokens::new();
 
         if !self.attributes.is_empty() {
@@ -118,6 +132,10 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Extension<'el> {
                 }
             }
 
+            for subscript in self.subscripts {
+                body.push(subscript);
+            }
+
             if !self.methods.is_empty() {
                 for method in self.methods {
                     body.push(method);
@@ -151,4 +169,27 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public extension Foo<T> : Super {\n}"), out);
     }
+
+    #[test]
+    fn test_conditional_conformance() {
+        let equatable = local("Equatable");
+
+        let mut c = Extension::new(local("Array"));
+        c.modifiers = vec![];
+        c.implements = vec![equatable.clone()];
+        c.where_clauses
+            .push(toks![local("Element"), " : ", equatable.clone()]);
+        c.where_clauses
+            .push(toks![local("Index"), " == ", local("Int")]);
+
+        let t: Tokens<Swift> = c.into();
+
+        assert_eq!(
+            Ok(concat!(
+                "extension Array : Equatable where Element : Equatable, Index == Int {\n",
+                "}\n",
+            )),
+            t.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
 }
@@ -8,6 +8,17 @@ use swift::Swift;
 use {Cons, Tokens};
 use {Element, IntoTokens};
 
+/// Storage qualifier of a field, which isn't an access modifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Storage {
+    /// `lazy`, initialized on first access.
+    Lazy,
+    /// `weak`, only valid for optional `var` fields.
+    Weak,
+    /// `unowned`.
+    Unowned,
+}
+
 /// Model for Swift Fields.
 #[derive(Debug, Clone)]
 pub struct Field<'el> {
@@ -27,6 +38,10 @@ pub struct Field<'el> {
     getter: Option<Tokens<'el, Swift<'el>>>,
     /// Setter for properties
     setter: Option<Tokens<'el, Swift<'el>>>,
+    /// Result builder content, one expression per line.
+    content: Option<Tokens<'el, Swift<'el>>>,
+    /// Storage qualifier.
+    storage: Option<Storage>,
 }
 
 impl<'el> Field<'el> {
@@ -47,7 +62,33 @@ impl<'el> Field<'el> {
             mutable: false,
             getter: None,
             setter: None,
+            content: None,
+            storage: None,
+        }
+    }
+
+    /// Create a computed property whose body is built by a result builder, like SwiftUI's
+    /// `var body : some View { ... }`.
+    ///
+    /// Each expression of the content is laid out on its own line, without separators.
+    pub fn result_builder<T, N, I>(ty: T, name: N, content: I) -> Field<'el>
+    where
+        T: Into<Swift<'el>>,
+        N: Into<Cons<'el>>,
+        I: IntoIterator,
+        I::Item: IntoTokens<'el, Swift<'el>>,
+    {
+        let mut body = Tokens::new();
+
+        for expr in content {
+            body.push(expr.into_tokens());
         }
+
+        let mut field = Field::new(ty, name);
+        field.modifiers = vec![];
+        field.mutable = true;
+        field.content = Some(body);
+        field
     }
 
     /// Set initializer for field.
@@ -58,6 +99,27 @@ impl<'el> Field<'el> {
         self.initializer = Some(initializer.into_tokens());
     }
 
+    /// Set getter for the property, making it computed.
+    pub fn getter<I>(&mut self, getter: I)
+    where
+        I: IntoTokens<'el, Swift<'el>>,
+    {
+        self.getter = Some(getter.into_tokens());
+    }
+
+    /// Set setter for the property.
+    pub fn setter<I>(&mut self, setter: I)
+    where
+        I: IntoTokens<'el, Swift<'el>>,
+    {
+        self.setter = Some(setter.into_tokens());
+    }
+
+    /// Set the storage qualifier of the field, like `lazy` or `weak`.
+    pub fn storage(&mut self, storage: Storage) {
+        self.storage = Some(storage);
+    }
+
     /// Set mutable for the field.
     pub fn mutable(&mut self, mutable: bool) {
         self.mutable = mutable;
@@ -72,6 +134,30 @@ impl<'el> Field<'el> {
     pub fn ty(&self) -> Swift<'el> {
         self.ty.clone()
     }
+
+    /// The initializer of the field, if any.
+    pub fn default_value(&self) -> Option<&Tokens<'el, Swift<'el>>> {
+        self.initializer.as_ref()
+    }
+
+    /// If the field is declared with `var`.
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+
+    /// If the field is a computed property.
+    pub fn is_computed(&self) -> bool {
+        self.getter.is_some() || self.content.is_some()
+    }
+
+    /// If the field is a stored instance property that a memberwise initializer must set.
+    ///
+    /// A `var` with an initializer is included, using the initializer as the parameter default.
+    pub fn is_memberwise(&self) -> bool {
+        !self.is_computed()
+            && !self.modifiers.contains(&Modifier::Static)
+            && (self.mutable || self.initializer.is_none())
+    }
 }
 
 into_tokens_impl_from!(Field<'el>, Swift<'el>);
@@ -80,11 +166,25 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Field<'el> {
     fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
         let mut tokens = Tokens::new();
 
+        if self.storage == Some(Storage::Weak) {
+            debug_assert!(
+                self.mutable && is_optional(&self.ty),
+                "weak fields must be optional vars: {}",
+                self.name.as_str()
+            );
+        }
+
         tokens.push_unless_empty(BlockComment(self.comments));
 
         tokens.append({
             let mut sig = Tokens::new();
             sig.extend(self.modifiers.into_tokens());
+            match self.storage {
+                Some(Storage::Lazy) => sig.append("lazy"),
+                Some(Storage::Weak) => sig.append("weak"),
+                Some(Storage::Unowned) => sig.append("unowned"),
+                None => {}
+            }
             if self.mutable {
                 sig.append("var")
             } else {
@@ -102,7 +202,12 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Field<'el> {
             sig.join_spacing()
         });
 
-        if self.getter.is_some() || self.setter.is_some() {
+        if let Some(content) = self.content {
+            tokens.append(Spacing);
+            tokens.append("{");
+            tokens.nested(content);
+            tokens.push("}");
+        } else if self.getter.is_some() || self.setter.is_some() {
             tokens.append(Spacing);
             tokens.append("{");
             tokens.nested({
@@ -112,7 +217,7 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Field<'el> {
                     if !getter.is_empty() {
                         body.append(Spacing);
                         body.append("{");
-                        body.push(getter);
+                        body.nested(getter);
                         body.push("}");
                     }
                 }
@@ -122,7 +227,7 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Field<'el> {
                     if !setter.is_empty() {
                         body.append(Spacing);
                         body.append("{");
-                        body.push(setter);
+                        body.nested(setter);
                         body.push("}");
                     }
                 }
@@ -135,6 +240,14 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Field<'el> {
     }
 }
 
+/// If the type is spelled as an optional, like `Foo?`.
+fn is_optional(ty: &Swift) -> bool {
+    match *ty {
+        Swift::Type { ref name } => name.path.last().unwrap_or(&name.name).ends_with('?'),
+        _ => false,
+    }
+}
+
 impl<'el> From<Field<'el>> for Element<'el, Swift<'el>> {
     fn from(f: Field<'el>) -> Self {
         Element::Append(Con::Owned(f.into_tokens()))
@@ -143,8 +256,8 @@ impl<'el> From<Field<'el>> for Element<'el, Swift<'el>> {
 
 #[cfg(test)]
 mod tests {
-    use swift::field::Field;
-    use swift::local;
+    use swift::field::{Field, Storage};
+    use swift::{imported, local, some};
     use tokens::Tokens;
 
     fn field() -> Field<'static> {
@@ -186,4 +299,81 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_imported_initializer() {
+        let mut field = Field::new(local("Int"), "foo");
+        field.initializer(toks![imported("Foo", "Bar"), "()"]);
+        let t: Tokens<_> = field.into();
+        assert_eq!(
+            Ok(String::from(
+                "import Foo\n\nprivate let foo : Int = Bar()\n"
+            )),
+            t.to_file()
+        );
+    }
+
+    #[test]
+    fn test_result_builder() {
+        let field = Field::result_builder(
+            some(imported("SwiftUI", "View")),
+            "body",
+            vec![
+                toks![imported("SwiftUI", "Text"), "(\"Hello\")"],
+                toks!["Spacer()"],
+            ],
+        );
+
+        assert!(field.is_computed());
+        assert!(!field.is_memberwise());
+
+        let t: Tokens<_> = field.into();
+        assert_eq!(
+            Ok(String::from(concat!(
+                "import SwiftUI\n",
+                "\n",
+                "var body : some View {\n",
+                "  Text(\"Hello\")\n",
+                "  Spacer()\n",
+                "}\n",
+            ))),
+            t.to_file()
+        );
+    }
+
+    #[test]
+    fn test_storage() {
+        let mut lazy = Field::new(local("Int"), "foo");
+        lazy.mutable = true;
+        lazy.storage(Storage::Lazy);
+        lazy.initializer("compute()");
+
+        let mut weak = Field::new(local("Delegate?"), "delegate");
+        weak.mutable = true;
+        weak.storage(Storage::Weak);
+
+        let mut unowned = Field::new(local("Owner"), "owner");
+        unowned.storage(Storage::Unowned);
+
+        let t = toks![lazy, weak, unowned].join_line_spacing();
+        assert_eq!(
+            Ok(String::from(concat!(
+                "private lazy var foo : Int = compute()\n",
+                "\n",
+                "private weak var delegate : Delegate?\n",
+                "\n",
+                "private unowned let owner : Owner",
+            ))),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_weak_must_be_optional_var() {
+        let mut weak = Field::new(local("Delegate"), "delegate");
+        weak.storage(Storage::Weak);
+        let _: Tokens<_> = weak.into();
+    }
 }
@@ -0,0 +1,77 @@
+//! Builder for interpolated strings.
+
+use swift::Swift;
+use {Cons, Custom, Formatter, IntoTokens, Tokens};
+
+/// Builder for an interpolated string, like `"text \(expr) more"`.
+#[derive(Debug, Clone)]
+pub struct Interpolated<'el> {
+    tokens: Tokens<'el, Swift<'el>>,
+}
+
+impl<'el> Interpolated<'el> {
+    /// Build a new empty interpolated string.
+    pub fn new() -> Interpolated<'el> {
+        Interpolated {
+            tokens: Tokens::new(),
+        }
+    }
+
+    /// Add a literal segment, which is escaped.
+    pub fn literal<L>(mut self, literal: L) -> Interpolated<'el>
+    where
+        L: Into<Cons<'el>>,
+    {
+        let mut escaped = String::new();
+
+        // writing to a string can't fail.
+        let _ = Swift::write_quoted_content(&mut Formatter::new(&mut escaped), &literal.into());
+
+        self.tokens.append(escaped);
+        self
+    }
+
+    /// Add an interpolated expression segment, which is written as-is.
+    pub fn expr<E>(mut self, expr: E) -> Interpolated<'el>
+    where
+        E: IntoTokens<'el, Swift<'el>>,
+    {
+        self.tokens.append(toks!["\\(", expr.into_tokens(), ")"]);
+        self
+    }
+}
+
+impl<'el> Default for Interpolated<'el> {
+    fn default() -> Self {
+        Interpolated::new()
+    }
+}
+
+into_tokens_impl_from!(Interpolated<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for Interpolated<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        toks!["\"", self.tokens, "\""]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interpolated;
+    use swift::{imported, Swift};
+    use tokens::Tokens;
+
+    #[test]
+    fn test_interpolated() {
+        let s = Interpolated::new()
+            .literal("say \"hi\" \\ ")
+            .expr(toks![imported("Foo", "Name"), ".value"])
+            .literal("!");
+
+        let t: Tokens<Swift> = s.into();
+        assert_eq!(
+            Ok("import Foo\n\n\"say \\\"hi\\\" \\\\ \\(Name.value)!\"\n"),
+            t.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+}
@@ -132,7 +132,7 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Method<'el> {
 #[cfg(test)]
 mod tests {
     use super::Method;
-    use swift::local;
+    use swift::{discardable_result, inline_always, local};
     use tokens::Tokens;
 
     fn build_method() -> Method<'static> {
@@ -187,4 +187,20 @@ mod tests {
             t.to_string()
         );
     }
+
+    #[test]
+    fn test_attributes() {
+        let mut m = build_return_method();
+        m.attribute(discardable_result());
+        m.attribute(inline_always());
+        m.body.push("return 42");
+
+        let t = Tokens::from(m);
+        assert_eq!(
+            Ok(String::from(
+                "@discardableResult\n@inline(__always)\npublic func foo<T>() -> Int {\n  return 42\n}"
+            )),
+            t.to_string()
+        );
+    }
 }
@@ -1,32 +1,46 @@
 //! Specialization for Swift code generation.
 
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
-use {Cons, Custom, Formatter, Tokens};
+use {Cons, Custom, Formatter, ImportOrder, IntoTokens, Tokens};
 
 mod argument;
+mod associated_type;
 mod class;
 mod comment;
 mod constructor;
+mod do_catch;
 mod enum_;
+mod enum_case;
 mod extension;
 mod field;
+mod interpolated;
 mod method;
 mod modifier;
+mod operator;
 mod protocol;
 mod struct_;
+mod subscript;
+mod switch;
 
 pub use self::argument::Argument;
+pub use self::associated_type::AssociatedType;
 pub use self::class::Class;
 pub use self::comment::BlockComment;
-pub use self::constructor::Constructor;
-pub use self::enum_::Enum;
+pub use self::constructor::{Constructor, Failability};
+pub use self::do_catch::{do_catch, Catch};
+pub use self::enum_::{all_cases, raw_value_init, Enum};
+pub use self::enum_case::EnumCase;
 pub use self::extension::Extension;
-pub use self::field::Field;
+pub use self::field::{Field, Storage};
+pub use self::interpolated::Interpolated;
 pub use self::method::Method;
 pub use self::modifier::Modifier;
+pub use self::operator::{Associativity, Fixity, Operator, PrecedenceGroup};
 pub use self::protocol::Protocol;
 pub use self::struct_::Struct;
+pub use self::subscript::Subscript;
+pub use self::switch::{switch, Case};
 
 /// Short primitive type.
 pub const SHORT: Swift<'static> = Swift::Primitive { primitive: "Int16" };
@@ -59,6 +73,11 @@ pub const BYTE: Swift<'static> = Swift::Primitive { primitive: "Int8" };
 /// Void primitive type.
 pub const VOID: Swift<'static> = Swift::Primitive { primitive: "Void" };
 
+/// The `Sendable` protocol, for conformance lists like `implements`.
+pub const SENDABLE: Swift<'static> = Swift::Primitive {
+    primitive: "Sendable",
+};
+
 /// Name of an imported type.
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct Name<'el> {
@@ -66,6 +85,21 @@ pub struct Name<'el> {
     module: Option<Cons<'el>>,
     /// Name imported.
     name: Cons<'el>,
+    /// Path of the name when nested.
+    path: Vec<Cons<'el>>,
+    /// Import the module with `@testable`.
+    testable: bool,
+    /// Only import the module if it's available, with `#if canImport(...)`.
+    can_import: bool,
+}
+
+/// The keyword of an opaque or existential type.
+#[derive(Debug, Clone, Copy, Hash, PartialOrd, Ord, PartialEq, Eq)]
+pub enum OpaqueKind {
+    /// An opaque type, `some Inner`.
+    Some,
+    /// An existential type, `any Inner`.
+    Any,
 }
 
 /// Swift token specialization.
@@ -94,16 +128,109 @@ pub enum Swift<'el> {
         /// Inner value of the array.
         inner: Box<Swift<'el>>,
     },
+    /// An opaque or existential type, `some <inner>` or `any <inner>`.
+    Opaque {
+        /// Keyword of the type.
+        keyword: OpaqueKind,
+        /// The protocol type.
+        inner: Box<Swift<'el>>,
+    },
+    /// A function type, `(<arguments>) -> <returns>`.
+    Function {
+        /// Types of the arguments.
+        arguments: Vec<Swift<'el>>,
+        /// Return type.
+        returns: Box<Swift<'el>>,
+        /// If the function is `@escaping`, as a closure argument.
+        escaping: bool,
+        /// If the function is `@Sendable`.
+        sendable: bool,
+    },
 }
 
 impl<'el> Swift<'el> {
-    fn type_imports<'a, 'b: 'a>(swift: &'b Swift<'b>, modules: &'a mut BTreeSet<&'b str>) {
+    /// Extend the type with a nested path, like `Foo.Bar`.
+    ///
+    /// Only applies to types, any other will return the same value.
+    pub fn path<P: Into<Cons<'el>>>(&self, part: P) -> Swift<'el> {
+        use self::Swift::*;
+
+        match *self {
+            Type { ref name } => {
+                let mut name = name.clone();
+                name.path.push(part.into());
+                Type { name }
+            }
+            ref swift => swift.clone(),
+        }
+    }
+
+    /// Import the module of the type with `@testable import`, for use in tests.
+    ///
+    /// Only applies to types, any other will return the same value.
+    pub fn testable(&self) -> Swift<'el> {
+        let mut swift = self.clone();
+
+        if let Swift::Type { ref mut name } = swift {
+            name.testable = true;
+        }
+
+        swift
+    }
+
+    /// Only import the module of the type if it's available, wrapping the import in
+    /// `#if canImport(...)`.
+    ///
+    /// Only applies to types, any other will return the same value.
+    pub fn can_import(&self) -> Swift<'el> {
+        let mut swift = self.clone();
+
+        if let Swift::Type { ref mut name } = swift {
+            name.can_import = true;
+        }
+
+        swift
+    }
+
+    /// Mark the function type as `@escaping`.
+    ///
+    /// Only applies to function types, any other will return the same value.
+    pub fn escaping(&self) -> Swift<'el> {
+        let mut swift = self.clone();
+
+        if let Swift::Function {
+            ref mut escaping, ..
+        } = swift
+        {
+            *escaping = true;
+        }
+
+        swift
+    }
+
+    /// Mark the function type as `@Sendable`.
+    ///
+    /// Only applies to function types, any other will return the same value.
+    pub fn sendable(&self) -> Swift<'el> {
+        let mut swift = self.clone();
+
+        if let Swift::Function {
+            ref mut sendable, ..
+        } = swift
+        {
+            *sendable = true;
+        }
+
+        swift
+    }
+
+    fn type_imports<'a, 'b: 'a>(swift: &'b Swift<'b>, modules: &'a mut Vec<&'b Name<'b>>) {
         use self::Swift::*;
 
         match *swift {
             Type { ref name, .. } => {
-                if let Some(module) = name.module.as_ref() {
-                    modules.insert(module);
+                if name.module.is_some() {
+                    modules.push(name);
                 }
             }
             Map {
@@ -112,17 +239,28 @@ impl<'el> Swift<'el> {
                 Self::type_imports(key, modules);
                 Self::type_imports(value, modules);
             }
-            Array { ref inner, .. } => {
+            Array { ref inner, .. } | Opaque { ref inner, .. } => {
                 Self::type_imports(inner, modules);
             }
-            Primitive { primitive } => {
+            Function {
+                ref arguments,
+                ref returns,
+                ..
+            } => {
+                for argument in arguments {
+                    Self::type_imports(argument, modules);
+                }
+
+                Self::type_imports(returns, modules);
+            }
+            Primitive { .. } => {
                 // do nothing
             }
         };
     }
 
-    fn imports<'a>(tokens: &'a Tokens<'a, Self>) -> Option<Tokens<'a, Self>> {
-        let mut modules = BTreeSet::new();
+    fn imports<'a>(tokens: &'a Tokens<'a, Self>, extra: &Extra) -> Option<Tokens<'a, Self>> {
+        let mut modules = Vec::new();
 
         for custom in tokens.walk_custom() {
             Self::type_imports(custom, &mut modules);
@@ -132,13 +270,41 @@ impl<'el> Swift<'el> {
             return None;
         }
 
+        // a module is testable if any use is, and guarded only if every use is.
+        let mut flags = BTreeMap::new();
+        let mut order = Vec::new();
+
+        for name in modules {
+            if let Some(module) = name.module.as_ref() {
+                // module names are case-sensitive, so only surrounding whitespace is trimmed.
+                let module = module.trim();
+                let flag = flags.entry(module).or_insert((false, true));
+                flag.0 |= name.testable;
+                flag.1 &= name.can_import;
+                order.push(module);
+            }
+        }
+
         let mut out = Tokens::new();
 
-        for module in modules {
+        for module in extra.import_order.apply(order) {
+            let (testable, can_import) = flags[module];
+
             let mut s = Tokens::new();
 
-            s.append("import ");
-            s.append(module);
+            if can_import {
+                s.push(toks!["#if canImport(", module, ")"]);
+            }
+
+            if testable {
+                s.push(toks!["@testable import ", module]);
+            } else {
+                s.push(toks!["import ", module]);
+            }
+
+            if can_import {
+                s.push("#endif");
+            }
 
             out.push(s);
         }
@@ -147,18 +313,43 @@ impl<'el> Swift<'el> {
     }
 }
 
+/// Extra data for Swift formatting.
+#[derive(Debug, Default)]
+pub struct Extra {
+    /// Order of the imports.
+    import_order: ImportOrder,
+}
+
+impl Extra {
+    /// Set the order of the imports, which are sorted by default.
+    pub fn import_order(&mut self, import_order: ImportOrder) {
+        self.import_order = import_order;
+    }
+}
+
 impl<'el> Custom for Swift<'el> {
-    type Extra = ();
+    type Extra = Extra;
+
+    fn requires_trailing_semicolon() -> bool {
+        false
+    }
 
     fn format(&self, out: &mut Formatter, extra: &mut Self::Extra, level: usize) -> fmt::Result {
         use self::Swift::*;
 
         match *self {
             Type {
-                name: Name { ref name, .. },
+                name: Name {
+                    ref name, ref path, ..
+                },
                 ..
             } => {
                 out.write_str(name)?;
+
+                for part in path {
+                    out.write_str(".")?;
+                    out.write_str(part)?;
+                }
             }
             Map {
                 ref key, ref value, ..
@@ -174,6 +365,41 @@ impl<'el> Custom for Swift<'el> {
                 inner.format(out, extra, level + 1)?;
                 out.write_str("]")?;
             }
+            Opaque { keyword, ref inner } => {
+                match keyword {
+                    OpaqueKind::Some => out.write_str("some ")?,
+                    OpaqueKind::Any => out.write_str("any ")?,
+                }
+
+                inner.format(out, extra, level)?;
+            }
+            Function {
+                ref arguments,
+                ref returns,
+                escaping,
+                sendable,
+            } => {
+                if escaping {
+                    out.write_str("@escaping ")?;
+                }
+
+                if sendable {
+                    out.write_str("@Sendable ")?;
+                }
+
+                out.write_str("(")?;
+
+                for (i, argument) in arguments.iter().enumerate() {
+                    if i > 0 {
+                        out.write_str(", ")?;
+                    }
+
+                    argument.format(out, extra, level + 1)?;
+                }
+
+                out.write_str(") -> ")?;
+                returns.format(out, extra, level + 1)?;
+            }
             Primitive { primitive } => {
                 out.write_str(primitive)?;
             }
@@ -182,9 +408,11 @@ impl<'el> Custom for Swift<'el> {
         Ok(())
     }
 
-    fn quote_string(out: &mut Formatter, input: &str) -> fmt::Result {
-        out.write_char('"')?;
+    fn write_quoted_open(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
 
+    fn write_quoted_content(out: &mut Formatter, input: &str) -> fmt::Result {
         for c in input.chars() {
             match c {
                 '\t' => out.write_str("\\t")?,
@@ -197,10 +425,15 @@ impl<'el> Custom for Swift<'el> {
             };
         }
 
-        out.write_char('"')?;
         Ok(())
     }
 
+    fn write_quoted_close(out: &mut Formatter) -> fmt::Result {
+        out.write_char('"')
+    }
+
+    /// Imports for every declaration are collected into a single block at the top of the file,
+    /// with each module imported once.
     fn write_file<'a>(
         tokens: Tokens<'a, Self>,
         out: &mut Formatter,
@@ -209,7 +442,7 @@ impl<'el> Custom for Swift<'el> {
     ) -> fmt::Result {
         let mut toks: Tokens<Self> = Tokens::new();
 
-        if let Some(imports) = Self::imports(&tokens) {
+        if let Some(imports) = Self::imports(&tokens, extra) {
             toks.push(imports);
         }
 
@@ -228,6 +461,9 @@ where
         name: Name {
             module: Some(module.into()),
             name: name.into(),
+            path: vec![],
+            testable: false,
+            can_import: false,
         },
     }
 }
@@ -241,6 +477,9 @@ where
         name: Name {
             module: None,
             name: name.into(),
+            path: vec![],
+            testable: false,
+            can_import: false,
         },
     }
 }
@@ -267,10 +506,141 @@ where
     }
 }
 
+/// Setup an opaque type, like `some View`.
+pub fn some<'a, I>(inner: I) -> Swift<'a>
+where
+    I: Into<Swift<'a>>,
+{
+    Swift::Opaque {
+        keyword: OpaqueKind::Some,
+        inner: Box::new(inner.into()),
+    }
+}
+
+/// Setup an existential type, like `any Collection`.
+pub fn any<'a, I>(inner: I) -> Swift<'a>
+where
+    I: Into<Swift<'a>>,
+{
+    Swift::Opaque {
+        keyword: OpaqueKind::Any,
+        inner: Box::new(inner.into()),
+    }
+}
+
+/// Setup a function type, like `(Int) -> Void`.
+pub fn function<'a, I, R>(arguments: I, returns: R) -> Swift<'a>
+where
+    I: IntoIterator,
+    I::Item: Into<Swift<'a>>,
+    R: Into<Swift<'a>>,
+{
+    Swift::Function {
+        arguments: arguments.into_iter().map(Into::into).collect(),
+        returns: Box::new(returns.into()),
+        escaping: false,
+        sendable: false,
+    }
+}
+
+/// Setup a builder for an interpolated string, like `"text \(expr) more"`.
+pub fn interpolated<'a>() -> Interpolated<'a> {
+    Interpolated::new()
+}
+
+/// Setup a runtime availability check, like `if #available(iOS 14, *) { ... } else { ... }`.
+///
+/// Each platform is given with its version, like `iOS 14`, and the wildcard `*` is added last.
+pub fn if_available<'a, I, P, T>(
+    platforms: I,
+    then: T,
+    else_body: Option<Tokens<'a, Swift<'a>>>,
+) -> Tokens<'a, Swift<'a>>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Cons<'a>>,
+    T: IntoTokens<'a, Swift<'a>>,
+{
+    let mut args: Tokens<'a, Swift<'a>> = Tokens::new();
+
+    for platform in platforms {
+        args.append(platform.into());
+    }
+
+    args.append("*");
+
+    let mut t = Tokens::new();
+    t.push(toks!["if #available(", args.join(", "), ") {"]);
+    t.nested(then.into_tokens());
+
+    if let Some(else_body) = else_body {
+        t.push("} else {");
+        t.nested(else_body);
+    }
+
+    t.push("}");
+    t
+}
+
+/// The `@objc` attribute, optionally with an explicit Objective-C name like `@objc(name)`.
+pub fn objc<'a>(name: Option<Cons<'a>>) -> Tokens<'a, Swift<'a>> {
+    match name {
+        Some(name) => toks!["@objc(", name, ")"],
+        None => toks!["@objc"],
+    }
+}
+
+/// The `@objcMembers` attribute.
+pub fn objc_members<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@objcMembers"]
+}
+
+/// The `@main` attribute, marking the entry point of a program.
+pub fn main_attribute<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@main"]
+}
+
+/// The `@frozen` attribute, for library evolution.
+///
+/// Attached to a [`Struct`] or [`Enum`] with their `attributes` method, it renders on its own line
+/// above the declaration.
+pub fn frozen<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@frozen"]
+}
+
+/// The `@usableFromInline` attribute, for internal declarations used by inlinable code.
+pub fn usable_from_inline<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@usableFromInline"]
+}
+
+/// The `@discardableResult` attribute, attached with [`Method::attribute`].
+pub fn discardable_result<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@discardableResult"]
+}
+
+/// The `@inlinable` attribute, attached with [`Method::attribute`].
+pub fn inlinable<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@inlinable"]
+}
+
+/// The `@inline(__always)` attribute, attached with [`Method::attribute`].
+pub fn inline_always<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@inline(__always)"]
+}
+
+/// The `@inline(never)` attribute, attached with [`Method::attribute`].
+pub fn inline_never<'a>() -> Tokens<'a, Swift<'a>> {
+    toks!["@inline(never)"]
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{array, imported, local, map, Swift};
-    use {Quoted, Tokens};
+    use super::{
+        any, array, frozen, function, if_available, imported, local, map, objc, objc_members, some,
+        usable_from_inline, Class, Enum, Extra, Field, Modifier, Struct, Swift, INTEGER, SENDABLE,
+        VOID,
+    };
+    use {ImportOrder, Quoted, Tokens};
 
     #[test]
     fn test_string() {
@@ -293,6 +663,196 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_path() {
+        let baz = imported("Foo", "Bar").path("Baz");
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(toks!(&baz));
+
+        assert_eq!(
+            Ok("import Foo\n\nBar.Baz\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_imports_deduplicated() {
+        let dbg = map(imported("Foo", "Key"), array(imported("Foo", "Debug")));
+        let other = imported(" Foo ", "Other");
+        let bar = imported("Bar", "Bar");
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(toks!(&bar, " ", &dbg, " ", &other));
+
+        let expected = Ok("import Bar\nimport Foo\n\nBar [Key: [Debug]] Other\n");
+
+        for _ in 0..4 {
+            assert_eq!(
+                expected,
+                toks.clone().to_file().as_ref().map(|s| s.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_import_order() {
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(toks!(
+            imported("UIKit", "UIView"),
+            " ",
+            imported("Foundation", "Date"),
+            " ",
+            imported("UIKit", "UIColor")
+        ));
+
+        let mut extra = Extra::default();
+        extra.import_order(ImportOrder::FirstUse);
+
+        assert_eq!(
+            Ok("import UIKit\nimport Foundation\n\nUIView Date UIColor\n"),
+            toks.clone()
+                .to_file_with(extra)
+                .as_ref()
+                .map(|s| s.as_str())
+        );
+        assert_eq!(
+            Ok("import Foundation\nimport UIKit\n\nUIView Date UIColor\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_library_evolution_attributes() {
+        let mut s = Struct::new("Point");
+        s.attributes(frozen());
+
+        let mut e = Enum::new("Shape");
+        e.attributes(frozen());
+
+        let mut c = Class::new("Cache");
+        c.modifiers = vec![Modifier::Internal];
+        c.attributes(usable_from_inline());
+
+        let toks: Tokens<Swift> =
+            toks![Tokens::from(s), Tokens::from(e), Tokens::from(c)].join_line_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "@frozen\n",
+                "public struct Point {\n",
+                "}\n",
+                "\n",
+                "@frozen\n",
+                "public enum Shape {\n",
+                "}\n",
+                "\n",
+                "@usableFromInline\n",
+                "internal class Cache {\n",
+                "}",
+            )),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_if_available() {
+        let toks: Tokens<Swift> = toks![
+            if_available(vec!["iOS 14", "macOS 11"], "useNewApi()", None),
+            if_available(vec!["iOS 15"], "useNewerApi()", Some(toks!["useOldApi()"])),
+        ];
+
+        assert_eq!(
+            Ok(concat!(
+                "if #available(iOS 14, macOS 11, *) {\n",
+                "  useNewApi()\n",
+                "}\n",
+                "if #available(iOS 15, *) {\n",
+                "  useNewerApi()\n",
+                "} else {\n",
+                "  useOldApi()\n",
+                "}",
+            )),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_function() {
+        let handler = function(vec![imported("Foundation", "Data"), INTEGER], VOID);
+
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(toks!(handler.clone()));
+        toks.push(toks!(handler.sendable()));
+        toks.push(toks!(handler.sendable().escaping()));
+        toks.push(toks!(function(Vec::<Swift>::new(), local("Int")).escaping()));
+
+        assert_eq!(
+            Ok(concat!(
+                "import Foundation\n",
+                "\n",
+                "(Data, Int32) -> Void\n",
+                "@Sendable (Data, Int32) -> Void\n",
+                "@escaping @Sendable (Data, Int32) -> Void\n",
+                "@escaping () -> Int\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_sendable_conformance() {
+        let mut s = Struct::new("Point");
+        s.implements.push(SENDABLE);
+
+        let toks: Tokens<Swift> = s.into();
+
+        assert_eq!(
+            Ok("public struct Point : Sendable {\n}"),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_testable_and_can_import() {
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(toks!(
+            imported("MyApp", "Model").testable(),
+            " ",
+            imported("UIKit", "UIView").can_import(),
+            " ",
+            imported("UIKit", "UIColor").can_import(),
+            " ",
+            imported("AppKit", "NSView").can_import(),
+            " ",
+            imported("AppKit", "NSColor"),
+            " ",
+            imported("MyApp", "Other")
+        ));
+
+        assert_eq!(
+            Ok(concat!(
+                "import AppKit\n",
+                "@testable import MyApp\n",
+                "#if canImport(UIKit)\n",
+                "import UIKit\n",
+                "#endif\n",
+                "\n",
+                "Model UIView UIColor NSView NSColor Other\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_objc() {
+        let toks: Tokens<Swift> =
+            toks!(objc_members(), objc(None), objc(Some("doFoo:".into()))).join_spacing();
+
+        assert_eq!(
+            Ok("@objcMembers @objc @objc(doFoo:)"),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
+
     #[test]
     fn test_array() {
         let dbg = array(imported("Foo", "Debug"));
@@ -316,4 +876,112 @@ mod tests {
             toks.to_file().as_ref().map(|s| s.as_str())
         );
     }
+
+    #[test]
+    fn test_multiple_declarations_shared_imports() {
+        let mut a = Class::new("A");
+        a.fields
+            .push(Field::new(imported("Foundation", "Date"), "date"));
+
+        let mut b = Struct::new("B");
+        b.fields
+            .push(Field::new(imported("Foundation", "URL"), "url"));
+
+        let mut c = Class::new("C");
+        c.fields.push(Field::new(
+            map(local("String"), array(imported("Foundation", "Data"))),
+            "data",
+        ));
+
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(a);
+        toks.push(b);
+        toks.push(c);
+        let toks = toks.join_line_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "import Foundation\n",
+                "\n",
+                "public class A {\n",
+                "  private let date : Date\n",
+                "}\n",
+                "\n",
+                "public struct B {\n",
+                "  private let url : URL\n",
+                "}\n",
+                "\n",
+                "public class C {\n",
+                "  private let data : [String: [Data]]\n",
+                "}\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_multiple_declarations_distinct_imports() {
+        let mut a = Class::new("A");
+        a.fields
+            .push(Field::new(imported("UIKit", "UIView"), "view"));
+
+        let mut b = Struct::new("B");
+        b.fields
+            .push(Field::new(imported("Foundation", "URL"), "url"));
+
+        let mut c = Class::new("C");
+        c.implements.push(imported("Combine", "ObservableObject"));
+        c.fields
+            .push(Field::new(imported("Foundation", "Date"), "date"));
+
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(a);
+        toks.push(b);
+        toks.push(c);
+        let toks = toks.join_line_spacing();
+
+        assert_eq!(
+            Ok(concat!(
+                "import Combine\n",
+                "import Foundation\n",
+                "import UIKit\n",
+                "\n",
+                "public class A {\n",
+                "  private let view : UIView\n",
+                "}\n",
+                "\n",
+                "public struct B {\n",
+                "  private let url : URL\n",
+                "}\n",
+                "\n",
+                "public class C : ObservableObject {\n",
+                "  private let date : Date\n",
+                "}\n",
+            )),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_opaque() {
+        let view = some(imported("SwiftUI", "View"));
+        let items = array(any(imported("Foo", "Item")));
+        let mut toks: Tokens<Swift> = Tokens::new();
+        toks.push(toks!(&view, " ", &items));
+
+        assert_eq!(
+            Ok("import Foo\nimport SwiftUI\n\nsome View [any Item]\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_char() {
+        let toks: Tokens<Swift> = toks!('a'.quoted(), '"'.quoted()).join_spacing();
+
+        assert_eq!(
+            Ok("\"a\" \"\\\"\""),
+            toks.to_string().as_ref().map(|s| s.as_str())
+        );
+    }
 }
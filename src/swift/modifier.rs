@@ -1,6 +1,6 @@
 /// A Swift modifier.
-use std::collections::BTreeSet;
-use {Custom, Element, IntoTokens, Tokens};
+use std::cmp::Ordering;
+use {Custom, Element, IntoTokens, ModifierOrder, Tokens};
 
 /// Model for Enum.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
@@ -31,6 +31,8 @@ pub enum Modifier {
     Override,
     /// required modifier
     Required,
+    /// dynamic modifier
+    Dynamic,
 }
 
 impl Modifier {
@@ -51,6 +53,35 @@ impl Modifier {
             Convenience => "convenience",
             Override => "override",
             Required => "required",
+            Dynamic => "dynamic",
+        }
+    }
+
+    /// If this is an access modifier, like `public` or `private`.
+    pub fn is_access(&self) -> bool {
+        self.rank() <= Modifier::Private.rank()
+    }
+}
+
+impl ModifierOrder for Modifier {
+    fn rank(&self) -> usize {
+        use self::Modifier::*;
+
+        match *self {
+            Open => 0,
+            Public => 1,
+            Internal => 2,
+            FilePrivate => 3,
+            Private => 4,
+            Override => 5,
+            Required => 6,
+            Convenience => 7,
+            Static => 8,
+            Class => 9,
+            Final => 10,
+            Dynamic => 11,
+            Mutating => 12,
+            Throws => 13,
         }
     }
 }
@@ -61,10 +92,33 @@ impl<'el, C: Custom> From<Modifier> for Element<'el, C> {
     }
 }
 
+impl Modifier {
+    /// De-duplicate and sort the given modifiers with the given comparator.
+    ///
+    /// Access modifiers are mutually exclusive, so only the most permissive one is kept.
+    pub fn render_order<F>(modifiers: Vec<Modifier>, compare: F) -> Vec<Modifier>
+    where
+        F: FnMut(&Modifier, &Modifier) -> Ordering,
+    {
+        let most_permissive = modifiers
+            .iter()
+            .filter(|m| m.is_access())
+            .min_by_key(|m| m.rank())
+            .cloned();
+
+        Modifier::sort_by(modifiers, compare)
+            .into_iter()
+            .filter(|m| !m.is_access() || Some(m) == most_permissive.as_ref())
+            .collect()
+    }
+}
+
+/// Modifiers are de-duplicated and written in the canonical order.
+///
+/// For a different order, sort them with [`Modifier::render_order`] and append them one by one.
 impl<'el, C: Custom> IntoTokens<'el, C> for Vec<Modifier> {
     fn into_tokens(self) -> Tokens<'el, C> {
-        self.into_iter()
-            .collect::<BTreeSet<_>>()
+        Modifier::render_order(self, Modifier::canonical_cmp)
             .into_iter()
             .map(Element::from)
             .collect()
@@ -74,8 +128,10 @@ impl<'el, C: Custom> IntoTokens<'el, C> for Vec<Modifier> {
 #[cfg(test)]
 mod tests {
     use super::Modifier;
+    use into_tokens::IntoTokens;
     use swift::Swift;
     use tokens::Tokens;
+    use Element;
 
     #[test]
     fn test_vec() {
@@ -85,4 +141,43 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public static final"), out);
     }
+
+    #[test]
+    fn test_dynamic() {
+        use self::Modifier::*;
+        let el: Tokens<Swift> = vec![Dynamic, Override, Public].into_tokens().join_spacing();
+        let s = el.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("public override dynamic"), out);
+    }
+
+    #[test]
+    fn test_exclusive_access() {
+        use self::Modifier::*;
+        let el: Tokens<Swift> = vec![Public, Final, Open, Private]
+            .into_tokens()
+            .join_spacing();
+        let s = el.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("open final"), out);
+
+        assert!(FilePrivate.is_access());
+        assert!(!Override.is_access());
+    }
+
+    #[test]
+    fn test_modifier_order() {
+        use self::Modifier::*;
+        let el: Tokens<Swift> =
+            Modifier::render_order(vec![Static, Private, Public, Final], |a, b| {
+                a.name().cmp(b.name())
+            })
+            .into_iter()
+            .map(Element::from)
+            .collect();
+
+        let s = el.join_spacing().to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(Ok("final public static"), out);
+    }
 }
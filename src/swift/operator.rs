@@ -0,0 +1,197 @@
+//! Data structures for custom operators.
+
+use swift::Swift;
+use {Cons, Element, IntoTokens, Tokens};
+
+/// Position of an operator relative to its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fixity {
+    /// `prefix`, before its operand.
+    Prefix,
+    /// `infix`, between its operands.
+    Infix,
+    /// `postfix`, after its operand.
+    Postfix,
+}
+
+/// Model for Swift operator declarations, like `infix operator <> : MyPrecedence`.
+#[derive(Debug, Clone)]
+pub struct Operator<'el> {
+    /// Position of the operator.
+    pub fixity: Fixity,
+    /// Precedence group, only valid for infix operators.
+    pub precedence_group: Option<Cons<'el>>,
+    /// Symbol of the operator.
+    symbol: Cons<'el>,
+}
+
+impl<'el> Operator<'el> {
+    /// Build a new operator declaration.
+    pub fn new<S>(fixity: Fixity, symbol: S) -> Operator<'el>
+    where
+        S: Into<Cons<'el>>,
+    {
+        Operator {
+            fixity,
+            precedence_group: None,
+            symbol: symbol.into(),
+        }
+    }
+}
+
+into_tokens_impl_from!(Operator<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for Operator<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        debug_assert!(
+            self.precedence_group.is_none() || self.fixity == Fixity::Infix,
+            "only infix operators have a precedence group: {}",
+            self.symbol.as_str()
+        );
+
+        let mut t = Tokens::new();
+
+        t.append(match self.fixity {
+            Fixity::Prefix => "prefix",
+            Fixity::Infix => "infix",
+            Fixity::Postfix => "postfix",
+        });
+
+        t.append("operator");
+        t.append(self.symbol);
+
+        if let Some(group) = self.precedence_group {
+            t.append(":");
+            t.append(group);
+        }
+
+        t.join_spacing()
+    }
+}
+
+/// How operators of the same precedence group are grouped without parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// `left`, grouped from the left.
+    Left,
+    /// `right`, grouped from the right.
+    Right,
+    /// `none`, can't be chained.
+    None,
+}
+
+/// Model for Swift precedence groups.
+#[derive(Debug, Clone)]
+pub struct PrecedenceGroup<'el> {
+    /// Groups which this group has a higher precedence than.
+    pub higher_than: Vec<Cons<'el>>,
+    /// Groups which this group has a lower precedence than.
+    pub lower_than: Vec<Cons<'el>>,
+    /// Associativity of the operators in the group.
+    pub associativity: Option<Associativity>,
+    /// Name of the group.
+    name: Cons<'el>,
+}
+
+impl<'el> PrecedenceGroup<'el> {
+    /// Build a new empty precedence group.
+    pub fn new<N>(name: N) -> PrecedenceGroup<'el>
+    where
+        N: Into<Cons<'el>>,
+    {
+        PrecedenceGroup {
+            higher_than: vec![],
+            lower_than: vec![],
+            associativity: None,
+            name: name.into(),
+        }
+    }
+
+    /// Name of the group.
+    pub fn name(&self) -> Cons<'el> {
+        self.name.clone()
+    }
+}
+
+into_tokens_impl_from!(PrecedenceGroup<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for PrecedenceGroup<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        let mut body: Tokens<'el, Swift<'el>> = Tokens::new();
+
+        if !self.higher_than.is_empty() {
+            let groups: Tokens<_> = self.higher_than.into_iter().map(Element::from).collect();
+            body.push(toks!["higherThan: ", groups.join(", ")]);
+        }
+
+        if !self.lower_than.is_empty() {
+            let groups: Tokens<_> = self.lower_than.into_iter().map(Element::from).collect();
+            body.push(toks!["lowerThan: ", groups.join(", ")]);
+        }
+
+        if let Some(associativity) = self.associativity {
+            body.push(match associativity {
+                Associativity::Left => "associativity: left",
+                Associativity::Right => "associativity: right",
+                Associativity::None => "associativity: none",
+            });
+        }
+
+        let mut t = Tokens::new();
+        t.push(toks!["precedencegroup ", self.name, " {"]);
+        t.nested(body);
+        t.push("}");
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Associativity, Fixity, Operator, PrecedenceGroup};
+    use swift::Swift;
+    use Tokens;
+
+    #[test]
+    fn test_operators() {
+        let mut group = PrecedenceGroup::new("CompositionPrecedence");
+        group.higher_than.push("AdditionPrecedence".into());
+        group.lower_than.push("MultiplicationPrecedence".into());
+        group.associativity = Some(Associativity::Left);
+
+        let mut infix = Operator::new(Fixity::Infix, "<>");
+        infix.precedence_group = Some(group.name());
+
+        let prefix = Operator::new(Fixity::Prefix, "√");
+
+        let t: Tokens<Swift> = toks![
+            Tokens::from(group),
+            Tokens::from(infix),
+            Tokens::from(prefix)
+        ]
+        .join_line_spacing();
+
+        assert_eq!(
+            Ok(String::from(concat!(
+                "precedencegroup CompositionPrecedence {\n",
+                "  higherThan: AdditionPrecedence\n",
+                "  lowerThan: MultiplicationPrecedence\n",
+                "  associativity: left\n",
+                "}\n",
+                "\n",
+                "infix operator <> : CompositionPrecedence\n",
+                "\n",
+                "prefix operator √",
+            ))),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_precedence_group_only_for_infix() {
+        let mut postfix = Operator::new(Fixity::Postfix, "!!");
+        postfix.precedence_group = Some("Foo".into());
+        let _: Tokens<Swift> = postfix.into();
+    }
+}
@@ -1,5 +1,6 @@
 //! Data structure for interfaces.
 
+use swift::associated_type::AssociatedType;
 use swift::field::Field;
 use swift::method::Method;
 use swift::modifier::Modifier;
@@ -12,6 +13,8 @@ use {Cons, Tokens};
 pub struct Protocol<'el> {
     /// Interface modifiers.
     pub modifiers: Vec<Modifier>,
+    /// Declared associated types.
+    pub associated_types: Vec<AssociatedType<'el>>,
     /// Declared methods.
     pub methods: Vec<Method<'el>>,
     /// Declared Properties
@@ -34,6 +37,7 @@ impl<'el> Protocol<'el> {
     {
         Protocol {
             modifiers: vec![Modifier::Public],
+            associated_types: vec![],
             methods: vec![],
             fields: vec![],
             extends: Tokens::new(),
@@ -95,6 +99,16 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Protocol<'el> {
         s.nested({
             let mut body = Tokens::new();
 
+            if !self.associated_types.is_empty() {
+                let mut types = Tokens::new();
+
+                for associated_type in self.associated_types {
+                    types.push(associated_type);
+                }
+
+                body.push(types);
+            }
+
             if !self.methods.is_empty() {
                 for method in self.methods {
                     body.push(method);
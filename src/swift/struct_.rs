@@ -1,10 +1,12 @@
 //! Data structure for classes.
 
+use swift::argument::Argument;
 use swift::constructor::Constructor;
 use swift::field::Field;
 use swift::method::Method;
 use swift::modifier::Modifier;
-use swift::Swift;
+use swift::subscript::Subscript;
+use swift::{main_attribute, Swift};
 use {Cons, IntoTokens};
 use {Element, Tokens};
 
@@ -17,12 +19,16 @@ pub struct Struct<'el> {
     pub fields: Vec<Field<'el>>,
     /// Declared methods.
     pub constructors: Vec<Constructor<'el>>,
+    /// Declared subscripts.
+    pub subscripts: Vec<Subscript<'el>>,
     /// Declared methods.
     pub methods: Vec<Method<'el>>,
     /// What this class implements.
     pub implements: Vec<Swift<'el>>,
     /// Generic parameters.
     pub parameters: Tokens<'el, Swift<'el>>,
+    /// Write out the memberwise initializer for all stored properties.
+    pub synthesize_memberwise_init: bool,
     /// Annotations for the constructor.
     attributes: Tokens<'el, Swift<'el>>,
     /// Name of class.
@@ -38,9 +44,11 @@ impl<'el> Struct<'el> {
         Struct {
             modifiers: vec![Modifier::Public],
             fields: vec![],
+            subscripts: vec![],
             methods: vec![],
             constructors: vec![],
             parameters: Tokens::new(),
+            synthesize_memberwise_init: false,
             attributes: Tokens::new(),
             name: name.into(),
             implements: vec![],
@@ -59,12 +67,48 @@ impl<'el> Struct<'el> {
     pub fn name(&self) -> Cons<'el> {
         self.name.clone()
     }
+
+    /// Make this the entry point of the program, with `@main` and a `static func main()`.
+    pub fn entry_point<B>(&mut self, body: B)
+    where
+        B: IntoTokens<'el, Swift<'el>>,
+    {
+        let mut main = Method::new("main");
+        main.modifiers.push(Modifier::Static);
+        main.body = body.into_tokens();
+
+        self.attributes.push(main_attribute());
+        self.methods.push(main);
+    }
+
+    /// Build the memberwise initializer for all stored properties.
+    pub fn memberwise_init(&self) -> Constructor<'el> {
+        let mut c = Constructor::new();
+
+        for field in self.fields.iter().filter(|f| f.is_memberwise()) {
+            let mut argument = Argument::new(field.ty(), field.var());
+
+            if let Some(default) = field.default_value() {
+                argument.initializer(default.clone());
+            }
+
+            c.arguments.push(argument);
+            c.body.push(toks!["self.", field.var(), " = ", field.var()]);
+        }
+
+        c
+    }
 }
 
 into_tokens_impl_from!(Struct<'el>, Swift<'el>);
 
 impl<'el> IntoTokens<'el, Swift<'el>> for Struct<'el> {
-    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+    fn into_tokens(mut self) -> Tokens<'el, Swift<'el>> {
+        if self.synthesize_memberwise_init {
+            let init = self.memberwise_init();
+            self.constructors.insert(0, init);
+        }
+
         let mut sig = Tokens::new();
 
         sig.extend(self.modifiers.into_tokens());
@@ -118,6 +162,10 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Struct<'el> {
                 }
             }
 
+            for subscript in self.subscripts {
+                body.push(subscript);
+            }
+
             if !self.methods.is_empty() {
                 for method in self.methods {
                     body.push(method);
@@ -136,7 +184,7 @@ impl<'el> IntoTokens<'el, Swift<'el>> for Struct<'el> {
 #[cfg(test)]
 mod tests {
     use swift::struct_::Struct;
-    use swift::Swift;
+    use swift::{local, Field, Swift};
     use Tokens;
 
     #[test]
@@ -149,4 +197,78 @@ mod tests {
         let out = s.as_ref().map(|s| s.as_str());
         assert_eq!(Ok("public struct Foo<T> {\n}"), out);
     }
+
+    #[test]
+    fn test_memberwise_init() {
+        let mut c = Struct::new("Foo");
+        c.synthesize_memberwise_init = true;
+        c.fields.push(Field::new(local("Int"), "foo"));
+
+        let mut computed = Field::new(local("Int"), "bar");
+        computed.mutable(true);
+        computed.getter("return foo");
+        c.fields.push(computed);
+
+        let mut initialized = Field::new(local("String"), "baz");
+        initialized.initializer("\"baz\"");
+        c.fields.push(initialized);
+
+        let mut defaulted = Field::new(local("Int"), "count");
+        defaulted.mutable(true);
+        defaulted.initializer("0");
+        c.fields.push(defaulted);
+
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "public struct Foo {\n",
+                "  private let foo : Int\n",
+                "\n",
+                "  private var bar : Int {\n",
+                "    get {\n",
+                "      return foo\n",
+                "    }\n",
+                "  }\n",
+                "\n",
+                "  private let baz : String = \"baz\"\n",
+                "\n",
+                "  private var count : Int = 0\n",
+                "\n",
+                "  public init(\n",
+                "    foo : Int,\n",
+                "    count : Int = 0\n",
+                "  ) {\n",
+                "    self.foo = foo\n",
+                "    self.count = count\n",
+                "  }\n",
+                "}",
+            )),
+            out
+        );
+    }
+
+    #[test]
+    fn test_entry_point() {
+        let mut c = Struct::new("App");
+        c.entry_point(toks!["print(\"Hello\")"]);
+
+        let t: Tokens<Swift> = c.into();
+
+        let s = t.to_string();
+        let out = s.as_ref().map(|s| s.as_str());
+        assert_eq!(
+            Ok(concat!(
+                "@main\n",
+                "public struct App {\n",
+                "  public static func main() {\n",
+                "    print(\"Hello\")\n",
+                "  }\n",
+                "}",
+            )),
+            out
+        );
+    }
 }
@@ -0,0 +1,206 @@
+//! Data structure for subscripts.
+
+use swift::argument::Argument;
+use swift::comment::BlockComment;
+use swift::modifier::Modifier;
+use swift::Swift;
+use {Cons, IntoTokens, Tokens};
+
+/// Model for Swift Subscripts.
+#[derive(Debug, Clone)]
+pub struct Subscript<'el> {
+    /// Subscript modifiers.
+    pub modifiers: Vec<Modifier>,
+    /// Arguments for the subscript.
+    pub arguments: Vec<Argument<'el>>,
+    /// Body of the getter.
+    pub getter: Tokens<'el, Swift<'el>>,
+    /// Body of the setter, if the subscript is not read-only.
+    pub setter: Option<Tokens<'el, Swift<'el>>>,
+    /// Comments associated with this subscript.
+    pub comments: Vec<Cons<'el>>,
+    /// Annotations for the subscript.
+    attributes: Tokens<'el, Swift<'el>>,
+    /// Return type.
+    returns: Swift<'el>,
+}
+
+impl<'el> Subscript<'el> {
+    /// Build a new read-only subscript.
+    pub fn new<R>(returns: R) -> Subscript<'el>
+    where
+        R: Into<Swift<'el>>,
+    {
+        use self::Modifier::*;
+
+        Subscript {
+            modifiers: vec![Public],
+            arguments: vec![],
+            getter: Tokens::new(),
+            setter: None,
+            comments: Vec::new(),
+            attributes: Tokens::new(),
+            returns: returns.into(),
+        }
+    }
+
+    /// Push an annotation.
+    pub fn attribute<A>(&mut self, attribute: A)
+    where
+        A: IntoTokens<'el, Swift<'el>>,
+    {
+        self.attributes.push(attribute.into_tokens());
+    }
+
+    /// The return type of the subscript.
+    pub fn returns(&self) -> Swift<'el> {
+        self.returns.clone()
+    }
+}
+
+into_tokens_impl_from!(Subscript<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for Subscript<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        let mut sig = Tokens::new();
+
+        sig.extend(self.modifiers.into_tokens());
+
+        sig.append({
+            let args: Vec<Tokens<Swift>> = self
+                .arguments
+                .into_iter()
+                .map(IntoTokens::into_tokens)
+                .collect();
+
+            let args: Tokens<Swift> = args.into_tokens();
+
+            toks!["subscript(", args.join(", "), ")"]
+        });
+
+        sig.append("->");
+        sig.append(self.returns);
+
+        let mut s = Tokens::new();
+
+        s.push_unless_empty(BlockComment(self.comments));
+        s.push_unless_empty(self.attributes);
+
+        let sig = sig.join_spacing();
+
+        match self.setter {
+            None if self.getter.is_empty() => {
+                s.push(toks![sig, " { get }"]);
+            }
+            None => {
+                s.push(toks![sig, " {"]);
+                s.nested(self.getter);
+                s.push("}");
+            }
+            Some(ref setter) if self.getter.is_empty() && setter.is_empty() => {
+                s.push(toks![sig, " { get set }"]);
+            }
+            Some(setter) => {
+                s.push(toks![sig, " {"]);
+                s.nested({
+                    let mut body = Tokens::new();
+                    body.push("get");
+                    if !self.getter.is_empty() {
+                        body.append(" {");
+                        body.nested(self.getter);
+                        body.push("}");
+                    }
+                    body.push("set");
+                    if !setter.is_empty() {
+                        body.append(" {");
+                        body.nested(setter);
+                        body.push("}");
+                    }
+                    body
+                });
+                s.push("}");
+            }
+        }
+
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Subscript;
+    use swift::{local, Argument, Modifier, INTEGER};
+    use tokens::Tokens;
+
+    fn build_subscript() -> Subscript<'static> {
+        let mut s = Subscript::new(local("T"));
+        s.arguments.push(Argument::new(INTEGER, "index"));
+        s
+    }
+
+    #[test]
+    fn test_read_only() {
+        let mut s = build_subscript();
+        s.modifiers.push(Modifier::Static);
+        s.getter.push("return items[index]");
+
+        let t = Tokens::from(s);
+        assert_eq!(
+            Ok(String::from(
+                "public static subscript(index : Int32) -> T {\n  return items[index]\n}"
+            )),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    fn test_requirement() {
+        let t = Tokens::from(build_subscript());
+        assert_eq!(
+            Ok(String::from("public subscript(index : Int32) -> T { get }")),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    fn test_setter() {
+        let mut s = build_subscript();
+        s.getter.push("return items[index]");
+        s.setter = Some(toks!["items[index] = newValue"]);
+
+        let t = Tokens::from(s);
+        assert_eq!(
+            Ok(String::from(concat!(
+                "public subscript(index : Int32) -> T {\n",
+                "  get {\n",
+                "    return items[index]\n",
+                "  }\n",
+                "  set {\n",
+                "    items[index] = newValue\n",
+                "  }\n",
+                "}",
+            ))),
+            t.to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_setter() {
+        let mut s = build_subscript();
+        s.getter.push("return items[index]");
+        s.setter = Some(Tokens::new());
+
+        let t = Tokens::from(s);
+        assert_eq!(
+            Ok(String::from(concat!(
+                "public subscript(index : Int32) -> T {\n",
+                "  get {\n",
+                "    return items[index]\n",
+                "  }\n",
+                "  set\n",
+                "}",
+            ))),
+            t.to_string()
+        );
+    }
+}
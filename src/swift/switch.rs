@@ -0,0 +1,125 @@
+//! Data structure for switch statements.
+
+use swift::Swift;
+use {IntoTokens, Tokens};
+
+/// A case of a switch statement.
+#[derive(Debug, Clone)]
+pub struct Case<'el> {
+    /// Patterns matched by the case, like `.a` or `.b(let x)`.
+    pub patterns: Vec<Tokens<'el, Swift<'el>>>,
+    /// Body of the case, `break` is emitted if it's empty.
+    pub body: Tokens<'el, Swift<'el>>,
+    /// If control falls through to the next case.
+    pub fallthrough: bool,
+}
+
+impl<'el> Case<'el> {
+    /// Build a new case matching the given pattern.
+    pub fn new<P>(pattern: P) -> Case<'el>
+    where
+        P: IntoTokens<'el, Swift<'el>>,
+    {
+        Case {
+            patterns: vec![pattern.into_tokens()],
+            body: Tokens::new(),
+            fallthrough: false,
+        }
+    }
+}
+
+into_tokens_impl_from!(Case<'el>, Swift<'el>);
+
+impl<'el> IntoTokens<'el, Swift<'el>> for Case<'el> {
+    fn into_tokens(self) -> Tokens<'el, Swift<'el>> {
+        let patterns: Tokens<'el, Swift<'el>> = self.patterns.into_tokens();
+
+        let mut t = Tokens::new();
+        t.push(toks!["case ", patterns.join(", "), ":"]);
+        t.nested(arm_body(self.body, self.fallthrough));
+        t
+    }
+}
+
+fn arm_body<'el>(body: Tokens<'el, Swift<'el>>, fallthrough: bool) -> Tokens<'el, Swift<'el>> {
+    let mut t = Tokens::new();
+
+    if body.is_empty() && !fallthrough {
+        t.push("break");
+    } else {
+        t.push(body);
+    }
+
+    if fallthrough {
+        t.push("fallthrough");
+    }
+
+    t
+}
+
+/// Setup a switch statement over the given expression, with an optional `default` arm.
+pub fn switch<'el, E, I>(
+    expr: E,
+    cases: I,
+    default: Option<Tokens<'el, Swift<'el>>>,
+) -> Tokens<'el, Swift<'el>>
+where
+    E: IntoTokens<'el, Swift<'el>>,
+    I: IntoIterator<Item = Case<'el>>,
+{
+    let mut t = Tokens::new();
+    t.push(toks!["switch ", expr.into_tokens(), " {"]);
+
+    for case in cases {
+        t.push(case);
+    }
+
+    if let Some(default) = default {
+        t.push("default:");
+        t.nested(arm_body(default, false));
+    }
+
+    t.push("}");
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{switch, Case};
+    use swift::{imported, Swift};
+    use tokens::Tokens;
+
+    #[test]
+    fn test_switch() {
+        let mut a = Case::new(".a");
+        a.patterns.push(toks![".b"]);
+        a.body.push("foo()");
+        a.fallthrough = true;
+
+        let mut c = Case::new(toks![".c(let x)"]);
+        c.body.push(toks![imported("Foo", "Log"), ".info(x)"]);
+
+        let empty = Case::new(".d");
+
+        let t: Tokens<Swift> = switch("self", vec![a, c, empty], Some(toks!["bar()"]));
+
+        assert_eq!(
+            Ok(concat!(
+                "import Foo\n",
+                "\n",
+                "switch self {\n",
+                "case .a, .b:\n",
+                "  foo()\n",
+                "  fallthrough\n",
+                "case .c(let x):\n",
+                "  Log.info(x)\n",
+                "case .d:\n",
+                "  break\n",
+                "default:\n",
+                "  bar()\n",
+                "}\n",
+            )),
+            t.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+}
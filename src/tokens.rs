@@ -10,14 +10,14 @@
 
 use con_::Con::{self, Borrowed, Owned};
 use element::Element::{Append, Nested, Push};
-use std::collections::LinkedList;
 use std::fmt;
 use std::fmt::Display;
 use std::iter::FromIterator;
+use std::mem;
 use std::rc::Rc;
 use std::result;
 use std::vec;
-use {Custom, Element, Formatter, IntoTokens, WriteTokens};
+use {Cons, Custom, Element, ElementVisitor, Formatter, IntoTokens, WriteTokens};
 
 /// A set of tokens.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -133,6 +133,14 @@ where
         self.elements.insert(pos, element.into());
     }
 
+    /// Insert the elements of the given tokens at the front, preserving their order.
+    pub fn prepend<T>(&mut self, tokens: T)
+    where
+        T: IntoTokens<'el, C>,
+    {
+        self.elements.splice(0..0, tokens.into_tokens().elements);
+    }
+
     /// Append the given element.
     pub fn append<E>(&mut self, element: E)
     where
@@ -145,6 +153,30 @@ where
         }
     }
 
+    /// Append the given tokens, preceded by a single space unless nothing has been appended yet.
+    pub fn append_spaced<T>(&mut self, tokens: T)
+    where
+        T: IntoTokens<'el, C>,
+    {
+        if !self.elements.is_empty() {
+            self.elements.push(Element::Spacing);
+        }
+
+        self.elements.push(Append(Owned(tokens.into_tokens())));
+    }
+
+    /// Append the element produced by the given builder.
+    ///
+    /// This is a fallible version that propagates the error returned by the builder.
+    pub fn try_append<E, X, B>(&mut self, builder: B) -> Result<(), X>
+    where
+        B: FnOnce() -> Result<E, X>,
+        E: Into<Element<'el, C>>,
+    {
+        self.append(builder()?);
+        Ok(())
+    }
+
     /// Append a reference to a definition.
     pub fn append_ref(&mut self, element: &'el Element<'el, C>) {
         self.elements.push(Element::Borrowed(element));
@@ -174,11 +206,11 @@ where
         self.elements.extend(it.into_iter());
     }
 
-    /// Walk over all elements.
+    /// Walk over all custom elements, in the order they are written.
     pub fn walk_custom(&self) -> WalkCustom<C> {
-        let mut queue = LinkedList::new();
-        queue.extend(self.elements.iter());
-        WalkCustom { queue: queue }
+        WalkCustom {
+            stack: self.elements.iter().rev().collect(),
+        }
     }
 
     /// Add an registered custom element that is _not_ rendered.
@@ -193,6 +225,233 @@ where
     }
 }
 
+impl<'el, C> Tokens<'el, C> {
+    /// Create tokens from an iterator of displayable values, each converted into an owned literal.
+    pub fn from_display_iter<I>(iter: I) -> Tokens<'el, C>
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        Tokens {
+            elements: iter
+                .into_iter()
+                .map(|item| Element::from(item.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl<'el, C> Tokens<'el, C> {
+    /// Mark the tokens as one logical unit, like a single argument.
+    ///
+    /// Groups are formatted exactly like their contents.
+    pub fn grouped(self) -> Tokens<'el, C> {
+        Tokens {
+            elements: vec![Element::Group(Owned(self))],
+        }
+    }
+
+    /// Label the tokens with where they were generated, like the name of the generator.
+    ///
+    /// Labels don't affect the output, but show up in [`Tokens::dump_tree`]. They are only kept
+    /// in builds with debug assertions enabled.
+    pub fn labeled<L>(self, label: L) -> Tokens<'el, C>
+    where
+        L: Into<Cons<'el>>,
+    {
+        if !cfg!(debug_assertions) {
+            return self;
+        }
+
+        Tokens {
+            elements: vec![Element::Labeled(label.into(), Owned(self))],
+        }
+    }
+
+    /// Remove empty elements and flatten appended tokens with a single element, without changing
+    /// the output.
+    ///
+    /// Only owned tokens are pruned, borrowed and refcounted tokens are kept as they are.
+    pub fn prune(&mut self) {
+        let elements = mem::take(&mut self.elements);
+        self.elements = elements
+            .into_iter()
+            .filter_map(Tokens::prune_element)
+            .collect();
+    }
+
+    fn prune_element(element: Element<'el, C>) -> Option<Element<'el, C>> {
+        use self::Element::*;
+
+        match element {
+            None => Option::None,
+            Append(Owned(mut tokens)) => {
+                tokens.prune();
+
+                match tokens.elements.len() {
+                    0 => Option::None,
+                    1 => tokens.elements.pop(),
+                    _ => Some(Append(Owned(tokens))),
+                }
+            }
+            Push(Owned(mut tokens)) => {
+                tokens.prune();
+                Some(Push(Owned(tokens)))
+            }
+            Nested(Owned(mut tokens)) => {
+                tokens.prune();
+                Some(Nested(Owned(tokens)))
+            }
+            Group(Owned(mut tokens)) => {
+                tokens.prune();
+                Some(Group(Owned(tokens)))
+            }
+            Labeled(label, Owned(mut tokens)) => {
+                tokens.prune();
+                Some(Labeled(label, Owned(tokens)))
+            }
+            Aligned(mut rows) => {
+                for &mut (ref mut left, ref mut right) in &mut rows {
+                    left.prune();
+                    right.prune();
+                }
+
+                Some(Aligned(rows))
+            }
+            element => Some(element),
+        }
+    }
+}
+
+impl<'el, C: fmt::Debug> Tokens<'el, C> {
+    /// Dump the element tree, including labels, one element per line.
+    pub fn dump_tree(&self) -> String {
+        let mut out = String::new();
+        self.dump_into(&mut out, 0);
+        out
+    }
+
+    fn dump_into(&self, out: &mut String, depth: usize) {
+        for element in &self.elements {
+            Tokens::dump_element(element, out, depth);
+        }
+    }
+
+    fn dump_element(element: &Element<'el, C>, out: &mut String, depth: usize) {
+        use self::Element::*;
+
+        let inner = match *element {
+            Append(ref tokens) => Some(("Append".to_string(), tokens.as_ref())),
+            Push(ref tokens) => Some(("Push".to_string(), tokens.as_ref())),
+            Nested(ref tokens) => Some(("Nested".to_string(), tokens.as_ref())),
+            Group(ref tokens) => Some(("Group".to_string(), tokens.as_ref())),
+            Labeled(ref label, ref tokens) => {
+                Some((format!("Labeled({:?})", label.as_str()), tokens.as_ref()))
+            }
+            _ => Option::None,
+        };
+
+        if let Some((name, tokens)) = inner {
+            dump_line(out, depth, &name);
+            tokens.dump_into(out, depth + 1);
+            return;
+        }
+
+        match *element {
+            Rc(ref element) => {
+                dump_line(out, depth, "Rc");
+                Tokens::dump_element(element, out, depth + 1);
+            }
+            Borrowed(element) => {
+                dump_line(out, depth, "Borrowed");
+                Tokens::dump_element(element, out, depth + 1);
+            }
+            Aligned(ref rows) => {
+                dump_line(out, depth, "Aligned");
+
+                for (left, right) in rows {
+                    dump_line(out, depth + 1, "Left");
+                    left.dump_into(out, depth + 2);
+                    dump_line(out, depth + 1, "Right");
+                    right.dump_into(out, depth + 2);
+                }
+            }
+            _ => dump_line(out, depth, &format!("{:?}", element)),
+        }
+    }
+}
+
+impl<'el, C> Tokens<'el, C> {
+    /// Visit every element in pre-order with the given visitor.
+    pub fn visit<V: ElementVisitor<'el, C>>(&self, visitor: &mut V) {
+        self.visit_with(visitor, 0)
+    }
+
+    fn visit_with<V: ElementVisitor<'el, C>>(&self, visitor: &mut V, depth: usize) {
+        for element in &self.elements {
+            Tokens::visit_element(element, visitor, depth);
+        }
+    }
+
+    fn visit_element<V>(element: &Element<'el, C>, visitor: &mut V, depth: usize)
+    where
+        V: ElementVisitor<'el, C>,
+    {
+        use self::Element::*;
+
+        if let Rc(ref element) = *element {
+            return Tokens::visit_element(element, visitor, depth);
+        }
+
+        if let Borrowed(element) = *element {
+            return Tokens::visit_element(element, visitor, depth);
+        }
+
+        visitor.visit_element(element, depth);
+
+        match *element {
+            Append(ref tokens) => {
+                visitor.visit_append(tokens.as_ref(), depth);
+                tokens.as_ref().visit_with(visitor, depth + 1);
+            }
+            Push(ref tokens) => {
+                visitor.visit_push(tokens.as_ref(), depth);
+                tokens.as_ref().visit_with(visitor, depth + 1);
+            }
+            Nested(ref tokens) => {
+                visitor.visit_nested(tokens.as_ref(), depth);
+                tokens.as_ref().visit_with(visitor, depth + 1);
+            }
+            Group(ref tokens) => {
+                visitor.visit_group(tokens.as_ref(), depth);
+                tokens.as_ref().visit_with(visitor, depth + 1);
+            }
+            Labeled(ref label, ref tokens) => {
+                visitor.visit_labeled(label, tokens.as_ref(), depth);
+                tokens.as_ref().visit_with(visitor, depth + 1);
+            }
+            Aligned(ref rows) => {
+                visitor.visit_aligned(rows, depth);
+
+                for (left, right) in rows {
+                    left.visit_with(visitor, depth + 1);
+                    right.visit_with(visitor, depth + 1);
+                }
+            }
+            Literal(ref literal) => visitor.visit_literal(literal, depth),
+            Quoted(ref quoted) => visitor.visit_quoted(quoted, depth),
+            QuotedChar(c) => visitor.visit_quoted_char(c, depth),
+            Verbatim(ref verbatim) => visitor.visit_verbatim(verbatim, depth),
+            Comment(ref comment) => visitor.visit_comment(comment, depth),
+            Custom(ref custom) => visitor.visit_custom(custom.as_ref(), depth),
+            Registered(ref custom) => visitor.visit_registered(custom.as_ref(), depth),
+            Rc(_) | Borrowed(_) => {}
+            None | PushSpacing | Line | Spacing | LineSpacing | BlankLines(_) | Indent
+            | Unindent => visitor.visit_whitespace(element, depth),
+        }
+    }
+}
+
 impl<'el, C> IntoIterator for Tokens<'el, C> {
     type Item = Element<'el, C>;
     type IntoIter = vec::IntoIter<Element<'el, C>>;
@@ -203,6 +462,20 @@ impl<'el, C> IntoIterator for Tokens<'el, C> {
 }
 
 impl<'el, C: Custom> Tokens<'el, C> {
+    /// Build a statement from the given body, terminated with a `;` if the language requires it.
+    pub fn statement<T>(body: T) -> Tokens<'el, C>
+    where
+        T: IntoTokens<'el, C>,
+    {
+        let mut t = body.into_tokens();
+
+        if C::requires_trailing_semicolon() {
+            t.elements.push(Element::Literal(";".into()));
+        }
+
+        t
+    }
+
     /// Format the tokens.
     pub fn format(&self, out: &mut Formatter, extra: &mut C::Extra, level: usize) -> fmt::Result {
         for element in &self.elements {
@@ -212,6 +485,48 @@ impl<'el, C: Custom> Tokens<'el, C> {
         Ok(())
     }
 
+    /// Format the tokens, passing each line through the given hook before it's written.
+    ///
+    /// Trailing whitespace is trimmed before the hook sees a line, and the last line is flushed
+    /// once formatting is done.
+    pub fn format_with_line_hook<F>(
+        &self,
+        write: &mut dyn fmt::Write,
+        extra: &mut C::Extra,
+        mut hook: F,
+    ) -> fmt::Result
+    where
+        F: FnMut(&mut String),
+    {
+        let mut out = Formatter::with_line_hook(write, &mut hook);
+        out.trim_trailing_whitespace(true);
+        self.format(&mut out, extra, 0usize)?;
+        out.flush()
+    }
+
+    /// Format token as file with the given extra, passing each line through the given hook
+    /// before it's written.
+    pub fn to_file_with_line_hook<F>(
+        self,
+        mut extra: C::Extra,
+        mut hook: F,
+    ) -> result::Result<String, fmt::Error>
+    where
+        F: FnMut(&mut String),
+    {
+        let mut output = String::new();
+
+        {
+            let mut out = Formatter::with_line_hook(&mut output, &mut hook);
+            out.trim_trailing_whitespace(true);
+            C::write_file(self, &mut out, &mut extra, 0usize)?;
+            out.new_line_unless_empty()?;
+            out.flush()?;
+        }
+
+        Ok(output)
+    }
+
     /// Format token as file with the given extra.
     pub fn to_file_with(self, mut extra: C::Extra) -> result::Result<String, fmt::Error> {
         let mut output = String::new();
@@ -237,6 +552,99 @@ impl<'el, E: Default, C: Custom<Extra = E>> Tokens<'el, C> {
     pub fn to_string(self) -> result::Result<String, fmt::Error> {
         self.to_string_with(C::Extra::default())
     }
+
+    /// Format token as file, split into lines.
+    ///
+    /// The trailing newline of the file doesn't produce an empty last line, but empty lines
+    /// inside the file are kept.
+    pub fn to_file_vec(self) -> result::Result<Vec<String>, fmt::Error> {
+        Ok(self.to_file()?.lines().map(String::from).collect())
+    }
+
+    /// Format the tokens, split into lines.
+    ///
+    /// Like [`Tokens::to_file_vec`], a trailing newline doesn't produce an empty last line.
+    pub fn to_string_vec(self) -> result::Result<Vec<String>, fmt::Error> {
+        Ok(self.to_string()?.lines().map(String::from).collect())
+    }
+
+    /// Estimate the length of the formatted tokens, without formatting them.
+    ///
+    /// Whitespace and escapes are approximated, so this is only suitable for things like
+    /// `String::with_capacity`. Imports added by `to_file` are not counted.
+    pub fn estimate_len(&self) -> usize {
+        self.estimate_len_with(&mut C::Extra::default(), 0)
+    }
+
+    fn estimate_len_with(&self, extra: &mut C::Extra, mut depth: usize) -> usize {
+        let mut len = 0;
+
+        for element in &self.elements {
+            len += Tokens::estimate_element(element, extra, &mut depth);
+        }
+
+        len
+    }
+
+    /// Estimate a single element, tracking changes to the indentation in `depth`.
+    fn estimate_element(
+        element: &Element<'el, C>,
+        extra: &mut C::Extra,
+        depth: &mut usize,
+    ) -> usize {
+        use self::Element::*;
+
+        match *element {
+            Rc(ref element) => Tokens::estimate_element(element, extra, depth),
+            Borrowed(element) => Tokens::estimate_element(element, extra, depth),
+            Append(ref tokens) | Group(ref tokens) | Labeled(_, ref tokens) => {
+                tokens.as_ref().estimate_len_with(extra, *depth)
+            }
+            Push(ref tokens) => 1 + *depth * 2 + tokens.as_ref().estimate_len_with(extra, *depth),
+            Nested(ref tokens) => {
+                2 + (*depth + 1) * 2 + tokens.as_ref().estimate_len_with(extra, *depth + 1)
+            }
+            Literal(ref literal) | Verbatim(ref literal) => literal.len(),
+            Quoted(ref literal) => literal.len() + 2,
+            QuotedChar(c) => c.len_utf8() + 2,
+            Comment(ref comment) => comment
+                .as_str()
+                .lines()
+                .map(|l| 2 + *depth * 2 + l.len() + 3)
+                .sum(),
+            Custom(ref custom) => {
+                let mut out = String::new();
+
+                match custom
+                    .as_ref()
+                    .format(&mut Formatter::new(&mut out), extra, 0)
+                {
+                    Ok(()) => out.len(),
+                    Err(_) => 0,
+                }
+            }
+            Aligned(ref rows) => rows
+                .iter()
+                .map(|(left, right)| {
+                    2 + *depth * 2
+                        + left.estimate_len_with(extra, *depth)
+                        + right.estimate_len_with(extra, *depth)
+                })
+                .sum(),
+            PushSpacing | Line | Spacing => 1,
+            LineSpacing => 2,
+            BlankLines(n) => n + 1,
+            Indent => {
+                *depth += 1;
+                0
+            }
+            Unindent => {
+                *depth = depth.saturating_sub(1);
+                0
+            }
+            Registered(_) | None => 0,
+        }
+    }
 }
 
 impl<'el, E: Default, C: Custom<Extra = E> + Clone> Display for Tokens<'el, C> {
@@ -256,10 +664,25 @@ where
     where
         E: Into<Element<'el, C>>,
     {
-        let element = element.into();
+        use self::Element::*;
+
+        // separators holding owned tokens are shared, so each gap doesn't deep-clone them.
+        let element = match element.into() {
+            element @ Append(Owned(_))
+            | element @ Push(Owned(_))
+            | element @ Nested(Owned(_))
+            | element @ Group(Owned(_))
+            | element @ Labeled(_, Owned(_))
+            | element @ Aligned(_) => Rc(::std::rc::Rc::new(element)),
+            element => element,
+        };
 
         let len = self.elements.len();
-        let mut it = self.elements.into_iter().filter(|e| *e != Element::None);
+        let mut it = self
+            .elements
+            .into_iter()
+            .filter(|e| *e != Element::None)
+            .peekable();
 
         let mut out: Vec<Element<'el, C>> = Vec::with_capacity(match len {
             v if v < 1 => v,
@@ -272,14 +695,31 @@ where
             return Tokens { elements: out };
         }
 
+        let mut element = Some(element);
+
         while let Some(next) = it.next() {
-            out.push(element.clone());
+            if it.peek().is_some() {
+                out.extend(element.clone());
+            } else {
+                out.extend(element.take());
+            }
+
             out.push(next);
         }
 
         Tokens { elements: out }
     }
 
+    /// Create tokens from an iterator of displayable values, joined on the given element.
+    pub fn join_display<I, E>(iter: I, element: E) -> Tokens<'el, C>
+    where
+        I: IntoIterator,
+        I::Item: Display,
+        E: Into<Element<'el, C>>,
+    {
+        Tokens::from_display_iter(iter).join(element)
+    }
+
     /// Join with spacing.
     pub fn join_spacing(self) -> Tokens<'el, C> {
         self.join(Element::Spacing)
@@ -289,6 +729,58 @@ where
     pub fn join_line_spacing(self) -> Tokens<'el, C> {
         self.join(Element::LineSpacing)
     }
+
+    /// Join with line spacing, using the given number of empty lines.
+    pub fn join_line_spacing_n(self, n: usize) -> Tokens<'el, C> {
+        self.join(Element::BlankLines(n))
+    }
+
+    /// Rebuild the tokens, replacing every custom and registered element through the given
+    /// function while preserving the structure.
+    pub fn map_custom<F>(self, mut f: F) -> Tokens<'el, C>
+    where
+        F: FnMut(C) -> C,
+    {
+        self.map_custom_with(&mut f)
+    }
+
+    fn map_custom_with<F>(self, f: &mut F) -> Tokens<'el, C>
+    where
+        F: FnMut(C) -> C,
+    {
+        Tokens {
+            elements: self
+                .elements
+                .into_iter()
+                .map(|e| Self::map_element(e, f))
+                .collect(),
+        }
+    }
+
+    fn map_element<F>(element: Element<'el, C>, f: &mut F) -> Element<'el, C>
+    where
+        F: FnMut(C) -> C,
+    {
+        use self::Element::*;
+
+        match element {
+            Rc(element) => Self::map_element(element.as_ref().clone(), f),
+            Borrowed(element) => Self::map_element(element.clone(), f),
+            Append(tokens) => Append(Owned(tokens.into_owned().map_custom_with(f))),
+            Push(tokens) => Push(Owned(tokens.into_owned().map_custom_with(f))),
+            Nested(tokens) => Nested(Owned(tokens.into_owned().map_custom_with(f))),
+            Group(tokens) => Group(Owned(tokens.into_owned().map_custom_with(f))),
+            Labeled(label, tokens) => Labeled(label, Owned(tokens.into_owned().map_custom_with(f))),
+            Aligned(rows) => Aligned(
+                rows.into_iter()
+                    .map(|(left, right)| (left.map_custom_with(f), right.map_custom_with(f)))
+                    .collect(),
+            ),
+            Custom(custom) => Custom(Owned(f(custom.into_owned()))),
+            Registered(custom) => Registered(Owned(f(custom.into_owned()))),
+            other => other,
+        }
+    }
 }
 
 impl<'el, C> IntoTokens<'el, C> for Tokens<'el, C> {
@@ -365,6 +857,32 @@ impl<'el, C> IntoTokens<'el, C> for String {
 
 into_tokens_impl_from_generic!(String);
 
+macro_rules! tuple_into_tokens {
+    ($($ty:ident => $var:ident),+) => {
+        /// Convert tuples of up to eight members, appending each member in order.
+        impl<'el, C, $($ty,)+> IntoTokens<'el, C> for ($($ty,)+)
+        where
+            $($ty: IntoTokens<'el, C>,)+
+        {
+            fn into_tokens(self) -> Tokens<'el, C> {
+                let ($($var,)+) = self;
+
+                Tokens {
+                    elements: vec![$(Element::from($var.into_tokens()),)+],
+                }
+            }
+        }
+    };
+}
+
+tuple_into_tokens!(A => a, B => b);
+tuple_into_tokens!(A => a, B => b, D => d);
+tuple_into_tokens!(A => a, B => b, D => d, E => e);
+tuple_into_tokens!(A => a, B => b, D => d, E => e, F => f);
+tuple_into_tokens!(A => a, B => b, D => d, E => e, F => f, G => g);
+tuple_into_tokens!(A => a, B => b, D => d, E => e, F => f, G => g, H => h);
+tuple_into_tokens!(A => a, B => b, D => d, E => e, F => f, G => g, H => h, I => i);
+
 impl<'el, C> FromIterator<&'el Element<'el, C>> for Tokens<'el, C> {
     fn from_iter<I: IntoIterator<Item = &'el Element<'el, C>>>(iter: I) -> Tokens<'el, C> {
         Tokens {
@@ -381,8 +899,22 @@ impl<'el, C> FromIterator<Element<'el, C>> for Tokens<'el, C> {
     }
 }
 
+impl<'el, C> Extend<Element<'el, C>> for Tokens<'el, C> {
+    fn extend<I: IntoIterator<Item = Element<'el, C>>>(&mut self, iter: I) {
+        self.elements.extend(iter);
+    }
+}
+
+/// Each set of tokens is appended, like [`Tokens::append`].
+impl<'el, C> Extend<Tokens<'el, C>> for Tokens<'el, C> {
+    fn extend<I: IntoIterator<Item = Tokens<'el, C>>>(&mut self, iter: I) {
+        self.elements.extend(iter.into_iter().map(Element::from));
+    }
+}
+
 pub struct WalkCustom<'el, C: 'el> {
-    queue: LinkedList<&'el Element<'el, C>>,
+    /// Elements left to visit, with the next one last.
+    stack: Vec<&'el Element<'el, C>>,
 }
 
 impl<'el, C: 'el> Iterator for WalkCustom<'el, C> {
@@ -392,16 +924,26 @@ impl<'el, C: 'el> Iterator for WalkCustom<'el, C> {
         use self::Element::*;
 
         // read until custom element is encountered.
-        while let Some(next) = self.queue.pop_front() {
+        while let Some(next) = self.stack.pop() {
             match *next {
                 Rc(ref element) => {
-                    self.queue.push_back(element.as_ref());
+                    self.stack.push(element.as_ref());
                 }
                 Borrowed(ref element) => {
-                    self.queue.push_back(element);
+                    self.stack.push(element);
+                }
+                Push(ref tokens)
+                | Nested(ref tokens)
+                | Append(ref tokens)
+                | Group(ref tokens)
+                | Labeled(_, ref tokens) => {
+                    self.stack.extend(tokens.as_ref().elements.iter().rev());
                 }
-                Push(ref tokens) | Nested(ref tokens) | Append(ref tokens) => {
-                    self.queue.extend(tokens.as_ref().elements.iter());
+                Aligned(ref rows) => {
+                    for (left, right) in rows.iter().rev() {
+                        self.stack.extend(right.elements.iter().rev());
+                        self.stack.extend(left.elements.iter().rev());
+                    }
                 }
                 Custom(ref custom) => return Some(custom.as_ref()),
                 Registered(ref custom) => return Some(custom.as_ref()),
@@ -413,10 +955,22 @@ impl<'el, C: 'el> Iterator for WalkCustom<'el, C> {
     }
 }
 
+/// Write a single line of a tree dump at the given depth.
+fn dump_line(out: &mut String, depth: usize, line: &str) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+
+    out.push_str(line);
+    out.push('\n');
+}
+
 #[cfg(test)]
 mod tests {
     use super::Tokens;
+    use con_::Con::Owned;
     use custom::Custom;
+    use element::Element;
 
     #[derive(Debug, Clone, PartialEq, Eq)]
     struct Lang(u32);
@@ -442,6 +996,229 @@ mod tests {
         assert_eq!("foo bar nope", toks.to_string().unwrap().as_str());
     }
 
+    #[test]
+    fn test_statement() {
+        use go::{self, Go};
+        use java::Java;
+        use python::Python;
+        use swift::Swift;
+
+        assert_eq!(
+            "return x;",
+            Tokens::<Java>::statement("return x").to_string().unwrap()
+        );
+        assert_eq!(
+            "return x",
+            Tokens::<Python>::statement("return x").to_string().unwrap()
+        );
+        assert_eq!(
+            "return x",
+            Tokens::<Go>::statement("return x")
+                .to_string_with(go::Extra::from_package("main"))
+                .unwrap()
+        );
+        assert_eq!(
+            "return x",
+            Tokens::<Swift>::statement("return x").to_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_join_tokens_separator() {
+        let toks: Tokens<()> = toks!("a", "b", "c").join(toks!(",", Element::Spacing));
+        assert_eq!("a, b, c", toks.to_string().unwrap().as_str());
+
+        let toks: Tokens<()> = toks!("a").join(toks!(",", Element::Spacing));
+        assert_eq!(toks!("a"), toks);
+    }
+
+    #[test]
+    fn test_tuples() {
+        use java::{local, Java};
+        use IntoTokens;
+
+        fn render<'el, T: IntoTokens<'el, Java<'el>>>(value: T) -> String {
+            value.into_tokens().to_string().unwrap()
+        }
+
+        let foo = local("Foo");
+
+        assert_eq!("Foo bar", render((&foo, " bar")));
+        assert_eq!(
+            "final Foo baz;",
+            render((
+                ("final", Element::Spacing),
+                local("Foo"),
+                " ",
+                String::from("baz"),
+                ";"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_append_spaced() {
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.append_spaced("public");
+        toks.append_spaced(toks!("static", Element::Spacing, "final"));
+        toks.append_spaced(String::from("int"));
+
+        assert_eq!(
+            "public static final int",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_line_hook() {
+        use java::{imported, Java};
+
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["class Foo {"]);
+        toks.nested(toks![imported("java.util", "List"), " items;"]);
+        toks.push("}");
+
+        let mut out = String::new();
+        toks.format_with_line_hook(&mut out, &mut Default::default(), |line| {
+            line.insert_str(0, "> ")
+        })
+        .unwrap();
+        assert_eq!("> class Foo {\n>   java.util.List items;\n> }", out);
+
+        let out = toks
+            .to_file_with_line_hook(Default::default(), |line| line.push('|'))
+            .unwrap();
+        assert_eq!(
+            "import java.util.List;|\n|\nclass Foo {|\n  List items;|\n}|\n",
+            out
+        );
+    }
+
+    #[test]
+    fn test_try_append() {
+        let mut toks: Tokens<()> = Tokens::new();
+
+        assert_eq!(Ok(()), toks.try_append(|| Ok::<_, ()>(toks!("foo", "bar"))));
+        assert_eq!(
+            Err("nope"),
+            toks.try_append(|| Err::<Tokens<()>, _>("nope"))
+        );
+
+        assert_eq!("foobar", toks.to_string().unwrap().as_str());
+    }
+
+    #[test]
+    fn test_display_iter() {
+        let toks: Tokens<()> = Tokens::from_display_iter(vec![1, 2, 3]);
+        assert_eq!("123", toks.to_string().unwrap().as_str());
+
+        let toks: Tokens<()> = Tokens::join_display([1, 2, 3], ", ");
+        assert_eq!("1, 2, 3", toks.to_string().unwrap().as_str());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_labeled() {
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.push(toks!["class Foo {"].labeled("class"));
+        toks.nested(toks!["foo();"]);
+
+        assert_eq!(
+            concat!(
+                "Push\n",
+                "  Labeled(\"class\")\n",
+                "    Literal(Borrowed(\"class Foo {\"))\n",
+                "Nested\n",
+                "  Literal(Borrowed(\"foo();\"))\n",
+            ),
+            toks.dump_tree()
+        );
+
+        assert_eq!(
+            "class Foo {\n  foo();\n",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_dump_tree_shared() {
+        let shared: Element<()> = Element::Push(Owned(toks!["a"].labeled("shared")));
+        let borrowed = Element::Literal("b".into());
+
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.append(::std::rc::Rc::new(shared));
+        toks.append(&borrowed);
+        toks.append(Element::Aligned(vec![(toks!["x"], toks!["y"])]));
+
+        assert_eq!(
+            concat!(
+                "Rc\n",
+                "  Push\n",
+                "    Labeled(\"shared\")\n",
+                "      Literal(Borrowed(\"a\"))\n",
+                "Borrowed\n",
+                "  Literal(Borrowed(\"b\"))\n",
+                "Aligned\n",
+                "  Left\n",
+                "    Literal(Borrowed(\"x\"))\n",
+                "  Right\n",
+                "    Literal(Borrowed(\"y\"))\n",
+            ),
+            toks.dump_tree()
+        );
+    }
+
+    #[test]
+    fn test_join_line_spacing_n() {
+        let toks: Tokens<()> = toks!["foo", "bar", "baz"];
+        assert_eq!(
+            "foo\n\n\nbar\n\n\nbaz",
+            toks.clone()
+                .join_line_spacing_n(2)
+                .to_string()
+                .unwrap()
+                .as_str()
+        );
+        assert_eq!(
+            "foo\nbar\nbaz",
+            toks.join_line_spacing_n(0).to_string().unwrap().as_str()
+        );
+
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.push("class Foo {");
+        toks.nested(toks!["foo();", "bar();"].join_line_spacing_n(2));
+        toks.push("}");
+        assert_eq!(
+            "class Foo {\n  foo();\n\n\n  bar();\n}",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_verbatim() {
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.push("class Foo {");
+        toks.nested(Element::Verbatim("// line 1\n//  line 2\n".into()));
+        toks.push("}");
+
+        assert_eq!(
+            "class Foo {\n// line 1\n//  line 2\n}",
+            toks.to_string().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_indent() {
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.push("foo {");
+        toks.append(Element::Indent);
+        toks.push("bar");
+        toks.append(Element::Unindent);
+        toks.push("}");
+
+        assert_eq!("foo {\n  bar\n}", toks.to_string().unwrap().as_str());
+    }
+
     #[test]
     fn test_walk_custom() {
         let mut toks: Tokens<Lang> = Tokens::new();
@@ -451,15 +1228,178 @@ mod tests {
         // static string
         toks.append("bar");
 
-        toks.nested(toks!("2:1", "2:2", toks!("3:1", "3:2"), Lang(2)));
+        toks.nested(toks!("2:1", "2:2", toks!("3:1", Lang(3), "3:2"), Lang(2)));
 
         // owned literal
         toks.append(String::from("nope"));
+        toks.append(Lang(4));
 
         let output: Vec<_> = toks.walk_custom().cloned().collect();
 
-        let expected = vec![Lang(1), Lang(2)];
+        let expected = vec![Lang(1), Lang(3), Lang(2), Lang(4)];
 
         assert_eq!(expected, output);
     }
+
+    #[test]
+    fn test_map_custom() {
+        use java::{imported, local, Java};
+
+        let foo = local("Foo");
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["class ", foo.clone(), " {"]);
+        toks.nested(toks![&foo, " value;"]);
+        toks.push("}");
+        toks.register(local("Foo"));
+
+        let toks = toks.map_custom(|java| match java {
+            Java::Local { ref name } if name.as_ref() == "Foo" => imported("com.example", "Bar"),
+            java => java,
+        });
+
+        assert_eq!(
+            Ok("import com.example.Bar;\n\nclass Bar {\n  Bar value;\n}\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_estimate_len() {
+        use java::{local, Java};
+        use quoted::Quoted;
+
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["class ", local("Foo"), " {"]);
+        toks.nested(toks!["String value = ", "hello".quoted(), ";"]);
+        toks.push("}");
+
+        let estimate = toks.estimate_len();
+        let len = toks.to_string().unwrap().len();
+
+        assert!(estimate >= len, "{} < {}", estimate, len);
+        assert!(estimate <= len + 16, "{} > {} + 16", estimate, len);
+        assert_eq!(0, Tokens::<Java>::new().estimate_len());
+    }
+
+    #[test]
+    fn test_estimate_len_shared() {
+        let shared = ::std::rc::Rc::new(Element::Literal("shared".into()));
+        let borrowed = Element::Literal("borrowed".into());
+
+        let mut toks: Tokens<()> = Tokens::new();
+        toks.append(Element::Rc(shared));
+        toks.append(Element::Borrowed(&borrowed));
+        assert_eq!(14, toks.estimate_len());
+
+        let joined: Tokens<()> = toks!("a", "b", "c").join(toks!(",", " "));
+        assert_eq!(7, joined.estimate_len());
+    }
+
+    #[test]
+    fn test_extend_trait() {
+        fn fill<E: Extend<T>, T>(target: &mut E, items: Vec<T>) {
+            target.extend(items);
+        }
+
+        let mut toks: Tokens<()> = Tokens::new();
+        fill(&mut toks, vec![toks!("foo"), toks!("bar")]);
+        fill(&mut toks, vec![Element::Spacing, Element::from("baz")]);
+
+        assert_eq!("foobar baz", toks.to_string().unwrap().as_str());
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut toks: Tokens<()> = toks!("body");
+        toks.prepend(toks!("a", "b"));
+        toks.prepend("header");
+
+        assert_eq!("headerabbody", toks.to_string().unwrap().as_str());
+    }
+
+    #[test]
+    fn test_prune() {
+        use java::Java;
+
+        let mut inner: Tokens<Java> = Tokens::new();
+        inner.append(Element::None);
+        inner.append("b");
+
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.append(Element::None);
+        toks.append("a");
+        toks.append(Tokens::new());
+        toks.append(inner);
+        toks.nested(toks![Element::None, "c"]);
+        toks.push(Tokens::new());
+        toks.push("d");
+
+        let expected = toks.clone().to_string();
+        toks.prune();
+
+        assert_eq!(expected, toks.clone().to_string());
+        assert_eq!(
+            toks![
+                "a",
+                "b",
+                Element::Nested(Owned(toks!["c"])),
+                Element::Push(Owned(Tokens::new())),
+                Element::Push(Owned(toks!["d"]))
+            ],
+            toks
+        );
+    }
+
+    #[test]
+    fn test_grouped() {
+        use java::{imported, local, Java};
+
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.append("foo(");
+        toks.append(toks![local("A"), " a"].grouped());
+        toks.append(", ");
+        toks.append(toks![imported("java.util", "List"), " b"].grouped());
+        toks.append(")");
+
+        assert_eq!(2, toks.dump_tree().matches("Group\n").count());
+
+        let toks = toks.map_custom(|java| java);
+
+        assert_eq!(
+            Ok("import java.util.List;\n\nfoo(A a, List b)\n"),
+            toks.to_file().as_ref().map(|s| s.as_str())
+        );
+    }
+
+    #[test]
+    fn test_to_vec() {
+        use java::{imported, Java};
+
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["class Foo {"]);
+        toks.nested(toks![imported("java.util", "List"), " a;"]);
+        toks.push("}");
+
+        assert_eq!(
+            Ok(vec![
+                String::from("import java.util.List;"),
+                String::from(""),
+                String::from("class Foo {"),
+                String::from("  List a;"),
+                String::from("}"),
+            ]),
+            toks.clone().to_file_vec()
+        );
+
+        assert_eq!(
+            Ok(vec![
+                String::from("class Foo {"),
+                String::from("  java.util.List a;"),
+                String::from("}"),
+            ]),
+            toks.to_string_vec()
+        );
+
+        assert_eq!(Ok(vec![]), Tokens::<Java>::new().to_file_vec());
+    }
 }
@@ -0,0 +1,108 @@
+//! Visitor over every element in a set of tokens.
+
+use {Cons, Element, Tokens};
+
+/// Callbacks for a pre-order traversal with [`Tokens::visit`].
+///
+/// Every callback is a no-op by default, and receives the number of enclosing sets of tokens as
+/// its depth. Refcounted and borrowed elements are transparent, and visited as the element they
+/// point to.
+#[allow(unused_variables)]
+pub trait ElementVisitor<'el, C> {
+    /// Called for every element, before the callback for its variant.
+    fn visit_element(&mut self, element: &Element<'el, C>, depth: usize) {}
+
+    /// Called for appended tokens, before they are visited.
+    fn visit_append(&mut self, tokens: &Tokens<'el, C>, depth: usize) {}
+
+    /// Called for pushed tokens, before they are visited.
+    fn visit_push(&mut self, tokens: &Tokens<'el, C>, depth: usize) {}
+
+    /// Called for nested tokens, before they are visited.
+    fn visit_nested(&mut self, tokens: &Tokens<'el, C>, depth: usize) {}
+
+    /// Called for grouped tokens, before they are visited.
+    fn visit_group(&mut self, tokens: &Tokens<'el, C>, depth: usize) {}
+
+    /// Called for labeled tokens, before they are visited.
+    fn visit_labeled(&mut self, label: &Cons<'el>, tokens: &Tokens<'el, C>, depth: usize) {}
+
+    /// Called for aligned rows, before the columns of each row are visited.
+    fn visit_aligned(&mut self, rows: &[(Tokens<'el, C>, Tokens<'el, C>)], depth: usize) {}
+
+    /// Called for a literal string.
+    fn visit_literal(&mut self, literal: &Cons<'el>, depth: usize) {}
+
+    /// Called for a quoted string.
+    fn visit_quoted(&mut self, quoted: &Cons<'el>, depth: usize) {}
+
+    /// Called for a quoted character.
+    fn visit_quoted_char(&mut self, c: char, depth: usize) {}
+
+    /// Called for a verbatim string.
+    fn visit_verbatim(&mut self, verbatim: &Cons<'el>, depth: usize) {}
+
+    /// Called for a comment.
+    fn visit_comment(&mut self, comment: &Cons<'el>, depth: usize) {}
+
+    /// Called for a language-specific item.
+    fn visit_custom(&mut self, custom: &C, depth: usize) {}
+
+    /// Called for a registered language-specific item, which isn't rendered.
+    fn visit_registered(&mut self, custom: &C, depth: usize) {}
+
+    /// Called for whitespace and indentation elements, and empty elements.
+    fn visit_whitespace(&mut self, element: &Element<'el, C>, depth: usize) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ElementVisitor;
+    use java::{imported, Java};
+    use {Cons, Element, Quoted, Tokens};
+
+    #[derive(Default)]
+    struct Stats {
+        elements: usize,
+        literals: Vec<String>,
+        quoted: Vec<String>,
+        customs: usize,
+        max_depth: usize,
+    }
+
+    impl<'el> ElementVisitor<'el, Java<'el>> for Stats {
+        fn visit_element(&mut self, _: &Element<'el, Java<'el>>, depth: usize) {
+            self.elements += 1;
+            self.max_depth = self.max_depth.max(depth);
+        }
+
+        fn visit_literal(&mut self, literal: &Cons<'el>, _: usize) {
+            self.literals.push(literal.to_string());
+        }
+
+        fn visit_quoted(&mut self, quoted: &Cons<'el>, _: usize) {
+            self.quoted.push(quoted.to_string());
+        }
+
+        fn visit_custom(&mut self, _: &Java<'el>, _: usize) {
+            self.customs += 1;
+        }
+    }
+
+    #[test]
+    fn test_visit() {
+        let list = imported("java.util", "List");
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["foo(", "bar".quoted(), ")"]);
+        toks.nested(toks![list, " baz"]);
+
+        let mut stats = Stats::default();
+        toks.visit(&mut stats);
+
+        assert_eq!(vec!["foo(", ")", " baz"], stats.literals);
+        assert_eq!(vec!["bar"], stats.quoted);
+        assert_eq!(1, stats.customs);
+        assert_eq!(7, stats.elements);
+        assert_eq!(1, stats.max_depth);
+    }
+}
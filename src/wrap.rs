@@ -0,0 +1,76 @@
+//! Word-wrapping of comment lines.
+
+use Cons;
+
+/// Wrap each line on whitespace so that it is at most `width` characters.
+///
+/// Blank lines are kept, and words longer than `width` are put on their own line unbroken.
+pub fn wrap_lines<'el>(lines: Vec<Cons<'el>>, width: usize) -> Vec<Cons<'el>> {
+    let mut out = Vec::new();
+
+    for line in lines {
+        if line.as_str().chars().count() <= width {
+            out.push(line);
+            continue;
+        }
+
+        let mut current = String::new();
+        let mut current_width = 0;
+
+        for word in line.as_str().split_whitespace() {
+            let word_width = word.chars().count();
+
+            if current_width > 0 && current_width + 1 + word_width > width {
+                out.push(Cons::from(current));
+                current = String::new();
+                current_width = 0;
+            }
+
+            if current_width > 0 {
+                current.push(' ');
+                current_width += 1;
+            }
+
+            current.push_str(word);
+            current_width += word_width;
+        }
+
+        out.push(Cons::from(current));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_lines;
+
+    #[test]
+    fn test_wrap_lines() {
+        let lines = wrap_lines(
+            vec![
+                "the quick brown fox jumps".into(),
+                "".into(),
+                "short".into(),
+                "a supercalifragilistic word".into(),
+            ],
+            10,
+        );
+
+        let lines: Vec<&str> = lines.iter().map(|l| l.as_str()).collect();
+
+        assert_eq!(
+            vec![
+                "the quick",
+                "brown fox",
+                "jumps",
+                "",
+                "short",
+                "a",
+                "supercalifragilistic",
+                "word",
+            ],
+            lines
+        );
+    }
+}
@@ -4,6 +4,7 @@ use super::custom::Custom;
 use super::formatter::Formatter;
 use super::tokens::Tokens;
 use std::fmt;
+use std::io;
 
 /// Helper trait to write tokens immediately to containers.
 pub trait WriteTokens {
@@ -28,7 +29,9 @@ impl<W: fmt::Write> WriteTokens for W {
         tokens: Tokens<'el, C>,
         extra: &mut C::Extra,
     ) -> fmt::Result {
-        tokens.format(&mut Formatter::new(self), extra, 0usize)
+        let mut formatter = Formatter::new(self);
+        formatter.trim_trailing_whitespace(true);
+        tokens.format(&mut formatter, extra, 0usize)
     }
 
     fn write_file<'el, C: Custom>(
@@ -37,8 +40,125 @@ impl<W: fmt::Write> WriteTokens for W {
         extra: &mut C::Extra,
     ) -> fmt::Result {
         let mut formatter = Formatter::new(self);
+        formatter.trim_trailing_whitespace(true);
         C::write_file(tokens, &mut formatter, extra, 0usize)?;
         formatter.new_line_unless_empty()?;
         Ok(())
     }
 }
+
+/// Helper trait to write tokens immediately to `io::Write` types, without buffering the output.
+pub trait WriteTokensIo {
+    /// Write the given tokens to the writer.
+    fn write_tokens_io<'el, C: Custom>(
+        &mut self,
+        tokens: Tokens<'el, C>,
+        extra: &mut C::Extra,
+    ) -> io::Result<()>;
+
+    /// Write the given tokens to the writer as a file.
+    fn write_file_io<'el, C: Custom>(
+        &mut self,
+        tokens: Tokens<'el, C>,
+        extra: &mut C::Extra,
+    ) -> io::Result<()>;
+}
+
+/// Adapter from `io::Write` to `fmt::Write` which keeps the underlying error.
+struct IoAdapter<'write, W: 'write> {
+    write: &'write mut W,
+    error: Option<io::Error>,
+}
+
+impl<'write, W: io::Write> IoAdapter<'write, W> {
+    fn new(write: &'write mut W) -> IoAdapter<'write, W> {
+        IoAdapter { write, error: None }
+    }
+
+    /// Convert the result of formatting, preferring the error raised by the writer.
+    // `io::Error::other` needs Rust 1.74.
+    #[allow(clippy::io_other_error)]
+    fn finish(self, result: fmt::Result) -> io::Result<()> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(self
+                .error
+                .unwrap_or_else(|| io::Error::new(io::ErrorKind::Other, "formatting failed"))),
+        }
+    }
+}
+
+impl<'write, W: io::Write> fmt::Write for IoAdapter<'write, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+impl<W: io::Write> WriteTokensIo for W {
+    fn write_tokens_io<'el, C: Custom>(
+        &mut self,
+        tokens: Tokens<'el, C>,
+        extra: &mut C::Extra,
+    ) -> io::Result<()> {
+        let mut adapter = IoAdapter::new(self);
+        let result = adapter.write_tokens(tokens, extra);
+        adapter.finish(result)
+    }
+
+    fn write_file_io<'el, C: Custom>(
+        &mut self,
+        tokens: Tokens<'el, C>,
+        extra: &mut C::Extra,
+    ) -> io::Result<()> {
+        let mut adapter = IoAdapter::new(self);
+        let result = adapter.write_file(tokens, extra);
+        adapter.finish(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WriteTokensIo;
+    use java::Java;
+    use std::io;
+    use tokens::Tokens;
+
+    struct Failing;
+
+    impl io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_file_io() {
+        let mut toks: Tokens<Java> = Tokens::new();
+        toks.push(toks!["class Foo {", "}"]);
+
+        let mut out = Vec::new();
+        out.write_file_io(toks.clone(), &mut Default::default())
+            .unwrap();
+        assert_eq!("class Foo {}\n", String::from_utf8(out).unwrap());
+
+        let mut out = Vec::new();
+        out.write_tokens_io(toks, &mut Default::default()).unwrap();
+        assert_eq!("class Foo {}", String::from_utf8(out).unwrap());
+    }
+
+    #[test]
+    fn test_write_io_error() {
+        let toks: Tokens<Java> = toks!["foo"];
+        let err = Failing
+            .write_tokens_io(toks, &mut Default::default())
+            .unwrap_err();
+        assert_eq!(io::ErrorKind::BrokenPipe, err.kind());
+    }
+}